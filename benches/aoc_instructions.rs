@@ -0,0 +1,160 @@
+//! Instruction and cache access counts of every registered day, parse and each variant of both
+//! parts, measured under valgrind's callgrind simulator. Unlike wall clock timings these don't
+//! depend on what else the machine is doing, so small changes show up reliably.
+//!
+//! Each phase runs in its own process under callgrind, which only counts what happens inside
+//! [`measured`], so reading and parsing the input for a part isn't included. Days are selected
+//! and their inputs resolved like the Criterion benches.
+
+mod common;
+
+use adventofcode_rs::aoclib::callgrind::{self, CallgrindStats};
+use adventofcode_rs::aoclib::runner::PhaseVisitor;
+use adventofcode_rs::registry;
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::{Command, ExitCode, Stdio};
+
+/// Directory holding the callgrind output of the latest and previous run of each phase.
+const OUTPUT_DIR: &str = "target/aoc-instructions";
+
+/// The only code callgrind counts, see `--toggle-collect`.
+#[inline(never)]
+fn measured(run: &mut dyn FnMut()) {
+    run();
+}
+
+/// Runs the one phase called `name`, inside [`measured`].
+struct RunPhase<'a> {
+    name: &'a str,
+    found: bool,
+}
+
+impl PhaseVisitor for RunPhase<'_> {
+    fn phase(&mut self, name: &str, run: &mut dyn FnMut()) {
+        if name == self.name {
+            measured(run);
+            self.found = true;
+        }
+    }
+}
+
+/// Collects the names of the phases without running them.
+struct PhaseNames(Vec<String>);
+
+impl PhaseVisitor for PhaseNames {
+    fn phase(&mut self, name: &str, _run: &mut dyn FnMut()) {
+        self.0.push(name.to_string());
+    }
+}
+
+/// Child process: run one phase of one day, e.g. `--aoc-phase "2025 Day 01" part01/default`.
+fn run_phase(label: &str, name: &str) -> ExitCode {
+    let Some(bench) = registry::days()
+        .into_iter()
+        .find(|day| common::label(day.as_ref()) == label)
+        .and_then(common::load)
+    else {
+        eprintln!("Cannot measure {}", label);
+        return ExitCode::FAILURE;
+    };
+    let mut visitor = RunPhase { name, found: false };
+    match bench.day.visit_phases(&bench.input, &mut visitor) {
+        Ok(()) if visitor.found => ExitCode::SUCCESS,
+        Ok(()) => {
+            eprintln!("{} has no phase {}", label, name);
+            ExitCode::FAILURE
+        }
+        Err(e) => {
+            eprintln!("{}: {}", label, e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Run one phase in a child process under callgrind, keeping the previous run's output for
+/// comparison.
+fn measure(
+    exe: &str,
+    label: &str,
+    name: &str,
+) -> Result<(CallgrindStats, Option<CallgrindStats>), String> {
+    let file_name = format!("{}-{}", label, name)
+        .replace([' ', '/'], "_")
+        .to_lowercase();
+    let output = Path::new(OUTPUT_DIR).join(format!("{}.out", file_name));
+    let previous = Path::new(OUTPUT_DIR).join(format!("{}.out.old", file_name));
+    fs::create_dir_all(OUTPUT_DIR)
+        .map_err(|e| format!("Failed to create {}: {}", OUTPUT_DIR, e))?;
+    if output.exists() {
+        fs::rename(&output, &previous)
+            .map_err(|e| format!("Failed to keep previous run: {}", e))?;
+    }
+
+    // setarch -R disables address space randomisation, which would otherwise add noise.
+    let status = Command::new("setarch")
+        .arg(env::consts::ARCH)
+        .arg("-R")
+        .arg("valgrind")
+        .arg("--tool=callgrind")
+        .args(callgrind::CACHE_ARGS)
+        .arg("--toggle-collect=aoc_instructions::measured")
+        .arg(format!("--callgrind-out-file={}", output.display()))
+        .arg(exe)
+        .args(["--aoc-phase", label, name])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .map_err(|e| format!("Failed to run valgrind: {}", e))?;
+    if !status.success() {
+        return Err(format!("callgrind run failed: {}", status));
+    }
+
+    let read = |path: &Path| -> Result<CallgrindStats, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        CallgrindStats::parse(&contents)
+    };
+    let stats = read(&output)?;
+    let previous = previous.exists().then(|| read(&previous)).transpose()?;
+    Ok((stats, previous))
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().collect();
+    if let [_, flag, label, name] = args.as_slice()
+        && flag == "--aoc-phase"
+    {
+        return run_phase(label, name);
+    }
+
+    let valgrind = Command::new("valgrind")
+        .arg("--version")
+        .stdout(Stdio::null())
+        .status();
+    if !valgrind.is_ok_and(|status| status.success()) {
+        eprintln!("valgrind was not found, skipping the instruction count benchmarks");
+        return ExitCode::SUCCESS;
+    }
+
+    let mut code = ExitCode::SUCCESS;
+    for bench in common::days() {
+        let mut names = PhaseNames(vec![]);
+        if let Err(e) = bench.day.visit_phases(&bench.input, &mut names) {
+            eprintln!("Skipping {}: {}", bench.label, e);
+            continue;
+        }
+        for name in names.0 {
+            println!("{}/{}", bench.label, name);
+            match measure(&args[0], &bench.label, &name) {
+                Ok((stats, previous)) => println!("{}", stats.compare(previous.as_ref())),
+                Err(e) => {
+                    eprintln!("  {}", e);
+                    code = ExitCode::FAILURE;
+                }
+            }
+        }
+    }
+    code
+}
@@ -0,0 +1,54 @@
+//! Day selection and input resolution shared by the bench targets.
+
+use adventofcode_rs::aoclib::input;
+use adventofcode_rs::aoclib::runner::AocDay;
+use adventofcode_rs::registry;
+use std::env;
+
+/// A registered day to benchmark, with the input the runner would read for it.
+pub struct BenchDay {
+    pub day: Box<dyn AocDay>,
+    pub label: String,
+    pub input: String,
+}
+
+/// Read a numeric filter from the environment.
+fn filter(var: &str) -> Option<u32> {
+    let value = env::var(var).ok()?;
+    Some(
+        value
+            .parse()
+            .unwrap_or_else(|_| panic!("{} must be a number, got '{}'", var, value)),
+    )
+}
+
+/// Name of a day's benchmark group, e.g. `2025 Day 01`.
+pub fn label(day: &dyn AocDay) -> String {
+    let (year, day_num) = day.year_day();
+    format!("{} Day {:02}", year, day_num)
+}
+
+/// Resolve the input of `day` like the runner does, or `None` (after saying why) if it has none.
+pub fn load(day: Box<dyn AocDay>) -> Option<BenchDay> {
+    let label = label(day.as_ref());
+    match input::load(&day.input_path()) {
+        Ok(Some(input)) => Some(BenchDay { day, label, input }),
+        Ok(None) => {
+            eprintln!("Skipping {}: no input at {}", label, day.input_path());
+            None
+        }
+        Err(e) => {
+            eprintln!("Skipping {}: {}", label, e);
+            None
+        }
+    }
+}
+
+/// The registered days selected by `AOC_BENCH_YEAR` and `AOC_BENCH_DAY`, skipping those without
+/// an input.
+pub fn days() -> Vec<BenchDay> {
+    registry::select(filter("AOC_BENCH_YEAR"), filter("AOC_BENCH_DAY"))
+        .into_iter()
+        .filter_map(load)
+        .collect()
+}
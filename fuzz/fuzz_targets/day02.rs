@@ -0,0 +1,6 @@
+#![no_main]
+
+use adventofcode_rs::aoclib::fuzz;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| fuzz::fuzz_day(2025, 2, data));
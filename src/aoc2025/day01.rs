@@ -1,5 +1,7 @@
 use crate::aoc2025::day01::Direction::{Left, Right};
+use crate::aoclib::generate::Rng;
 use crate::aoclib::runner::Runner;
+use crate::aoclib::scaling::Complexity;
 use std::fmt::Debug;
 use std::str::FromStr;
 
@@ -25,13 +27,18 @@ impl FromStr for AdventOfCode2025Day01 {
         let directions: Result<Vec<Direction>, String> = s
             .lines()
             .map(|line| {
-                let value = line[1..]
+                let mut chars = line.chars();
+                let direction = chars.next();
+                let value = chars
+                    .as_str()
                     .parse::<i32>()
-                    .map_err(|_| format!("Invalid number in line: {}", line))?;
+                    .ok()
+                    .filter(|value| *value >= 0)
+                    .ok_or_else(|| format!("Invalid number in line: {}", line))?;
 
-                match &line[0..1] {
-                    "L" => Ok(Left(value)),
-                    "R" => Ok(Right(value)),
+                match direction {
+                    Some('L') => Ok(Left(value)),
+                    Some('R') => Ok(Right(value)),
                     _ => Err(format!("Invalid direction in line: {}", line)),
                 }
             })
@@ -44,19 +51,23 @@ impl FromStr for AdventOfCode2025Day01 {
 }
 
 impl Runner for AdventOfCode2025Day01 {
-    type Output = i32;
+    type Output = i64;
 
     fn name(&self) -> (u32, u32) {
         (2025, 1)
     }
 
-    fn part01(&self) -> i32 {
+    fn title(&self) -> Option<&'static str> {
+        Some("Secret Entrance")
+    }
+
+    fn part01(&self) -> i64 {
         self.directions
             .iter()
             .fold((0, Self::DIAL_START_POSITION), |acc, direction| {
                 let position = match direction {
-                    Left(steps) => (acc.1 - steps).rem_euclid(100),
-                    Right(steps) => (acc.1 + steps).rem_euclid(100),
+                    Left(steps) => (acc.1 - steps % 100).rem_euclid(100),
+                    Right(steps) => (acc.1 + steps % 100).rem_euclid(100),
                 };
 
                 match position {
@@ -67,14 +78,15 @@ impl Runner for AdventOfCode2025Day01 {
             .0
     }
 
-    fn part02(&self) -> i32 {
+    fn part02(&self) -> i64 {
+        // The dial's absolute position can leave the range of i32 after enough rotations.
         self.directions
             .iter()
-            .scan(50i32, |abs_dial, rotation| {
+            .scan(Self::DIAL_START_POSITION as i64, |abs_dial, rotation| {
                 let start = *abs_dial;
                 match rotation {
-                    Left(amount) => *abs_dial -= amount,
-                    Right(amount) => *abs_dial += amount,
+                    Left(amount) => *abs_dial -= *amount as i64,
+                    Right(amount) => *abs_dial += *amount as i64,
                 }
                 let end = *abs_dial;
                 if end > start {
@@ -87,6 +99,18 @@ impl Runner for AdventOfCode2025Day01 {
             })
             .sum()
     }
+
+    fn complexity(&self, _part: u32) -> Option<Complexity> {
+        Some(Complexity::Linear)
+    }
+
+    /// `size` rotations of up to 999 clicks.
+    fn generate(&self, size: usize, rng: &mut Rng) -> Option<String> {
+        let rotations: String = (0..size)
+            .map(|_| format!("{}{}\n", rng.pick(&['L', 'R']), rng.range(1..=999)))
+            .collect();
+        Some(rotations)
+    }
 }
 
 #[cfg(test)]
@@ -94,7 +118,7 @@ mod tests {
     use crate::aoc2025::day01::{AdventOfCode2025Day01, Direction};
     use crate::aoclib::runner::Runner;
 
-    const TEST_INPUT: &str = include_str!("../../input/test/day01.input");
+    const TEST_INPUT: &str = include_str!("../../input/test/day01/example.input");
 
     #[test]
     fn direction() {
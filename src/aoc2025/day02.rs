@@ -1,4 +1,6 @@
-use crate::aoclib::runner::Runner;
+use crate::aoclib::generate::Rng;
+use crate::aoclib::runner::{Runner, Variant};
+use crate::aoclib::scaling::Complexity;
 use std::collections::BTreeSet;
 use std::str::FromStr;
 
@@ -17,31 +19,29 @@ impl Range {
     ///
     /// Note: this method uses strings comparison and walking the entire range, which is slower than
     /// a numeric approach.
-    #[allow(dead_code)]
-    pub fn sum_of_invalids(&self) -> u64 {
+    pub fn sum_of_invalids(&self) -> u128 {
         (self.start..=self.end)
             .filter(|&i| {
                 let s = i.to_string();
                 let len = s.len();
                 len % 2 == 0 && s[..len / 2] == s[len / 2..]
             })
-            .fold(u64::default(), |mut acc, i| {
-                acc += i;
+            .fold(u128::default(), |mut acc, i| {
+                acc += i as u128;
                 acc
             })
     }
 
     /// AOC Day 02 Part 01
     ///
-    /// Sum up all the invalid numbers which are designated as repeating values of the upper and
-    /// half of the number in the range.
-    ///
-    /// Note: this method uses strings comparison and walking the entire range, which is slower than
-    /// a numeric approach.
-    pub fn sum_of_invalids_fast(&self) -> u64 {
+    /// Same sum as [`Range::sum_of_invalids`], computed without walking the range. A number made
+    /// of an `n` digit half repeated twice is that half times `10^n + 1`, so only the halves whose
+    /// multiple falls within the range are visited.
+    pub fn sum_of_invalids_fast(&self) -> u128 {
         let mut sum = 0;
 
-        for number_length in 1..=9 {
+        // u64 holds up to 20 digits, so the repeated half has at most 10.
+        for number_length in 1..=10 {
             let multiplier = 10u64.pow(number_length) + 1;
 
             // Calculate the minimum bound on the prefix that we would find dupes.
@@ -51,11 +51,11 @@ impl Range {
             let end_prefix = 10u64.pow(number_length) - 1;
 
             for prefix in start_prefix..=end_prefix {
-                let value = prefix * multiplier;
-                if value > self.end {
-                    break;
-                }
-                sum += value;
+                let value = match prefix.checked_mul(multiplier) {
+                    Some(v) if v <= self.end => v,
+                    _ => break,
+                };
+                sum += value as u128;
             }
         }
         sum
@@ -68,8 +68,7 @@ impl Range {
     ///
     /// Note: this method uses strings comparison and walking the entire range, which is slower than
     /// a numeric approach.
-    #[allow(dead_code)]
-    pub fn sum_of_multi_invalids(&self) -> u64 {
+    pub fn sum_of_multi_invalids(&self) -> u128 {
         (self.start..=self.end)
             .filter(|&i| {
                 let s = i.to_string();
@@ -81,16 +80,16 @@ impl Range {
                             .all(|chunk| chunk == &s.as_bytes()[..size])
                 })
             })
-            .fold(u64::default(), |mut acc, i| {
-                acc += i;
+            .fold(u128::default(), |mut acc, i| {
+                acc += i as u128;
                 acc
             })
     }
 
-    pub fn sum_of_multi_invalids_fast(&self) -> u64 {
+    pub fn sum_of_multi_invalids_fast(&self) -> u128 {
         let mut invalid_numbers = BTreeSet::new();
 
-        for l in 1..=9 {
+        for l in 1..=10 {
             let p_base = 10u64.pow(l);
             let min_p = 10u64.pow(l - 1);
             let max_p = 10u64.pow(l) - 1;
@@ -129,7 +128,7 @@ impl Range {
             }
         }
 
-        invalid_numbers.iter().sum()
+        invalid_numbers.iter().map(|&n| n as u128).sum()
     }
 }
 
@@ -173,12 +172,17 @@ impl FromStr for AdventOfCode2025Day02 {
 }
 
 impl Runner for AdventOfCode2025Day02 {
-    type Output = u64;
+    // Sums over wide ranges don't fit in a u64.
+    type Output = u128;
 
     fn name(&self) -> (u32, u32) {
         (2025, 2)
     }
 
+    fn title(&self) -> Option<&'static str> {
+        Some("Gift Shop")
+    }
+
     fn part01(&self) -> Self::Output {
         self.ranges
             .iter()
@@ -192,14 +196,47 @@ impl Runner for AdventOfCode2025Day02 {
             .map(|r| r.sum_of_multi_invalids_fast())
             .sum()
     }
+
+    fn variants(&self, part: u32) -> Vec<Variant<Self>> {
+        match part {
+            1 => vec![
+                ("fast", Self::part01),
+                ("slow", |day| day.ranges.iter().map(Range::sum_of_invalids).sum()),
+            ],
+            _ => vec![
+                ("fast", Self::part02),
+                ("slow", |day| day.ranges.iter().map(Range::sum_of_multi_invalids).sum()),
+            ],
+        }
+    }
+
+    /// The fast variants only visit the invalid IDs of each range, so they are linear in the
+    /// number of ranges when ranges have a bounded width.
+    fn complexity(&self, _part: u32) -> Option<Complexity> {
+        Some(Complexity::Linear)
+    }
+
+    /// `size` ranges of IDs with up to 10 digits.
+    fn generate(&self, size: usize, rng: &mut Rng) -> Option<String> {
+        let ranges: Vec<String> = (0..size)
+            .map(|_| {
+                let digits = rng.range(1..=10) as u32;
+                let start = rng.range(10u64.pow(digits - 1)..=10u64.pow(digits) - 1);
+                let end = start + rng.range(0..=start.min(1_000_000));
+                format!("{}-{}", start, end)
+            })
+            .collect();
+        Some(format!("{}\n", ranges.join(",")))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::aoc2025::day02::{AdventOfCode2025Day02, Range};
     use crate::aoclib::runner::Runner;
+    use proptest::prelude::*;
 
-    const TEST_INPUT: &str = include_str!("../../input/test/day02.input");
+    const TEST_INPUT: &str = include_str!("../../input/test/day02/example.input");
 
     #[test]
     fn test_range_from_str() {
@@ -234,9 +271,55 @@ mod tests {
         assert_eq!(1227775554, day02.part01());
     }
 
+    #[test]
+    fn test_variants() {
+        let day02 = TEST_INPUT.parse::<AdventOfCode2025Day02>().unwrap();
+        for part in [1, 2] {
+            let answers: Vec<u128> = day02.variants(part).iter().map(|(_, f)| f(&day02)).collect();
+            assert_eq!(2, answers.len());
+            assert_eq!(answers[0], answers[1]);
+        }
+    }
+
     #[test]
     fn test_part02() {
         let day02 = TEST_INPUT.parse::<AdventOfCode2025Day02>().unwrap();
         assert_eq!(4174379265, day02.part02());
     }
+
+    /// Ranges narrow enough for the naive versions to walk, starting anywhere from small
+    /// numbers, around each digit-length boundary and around numbers made of a repeated half, to
+    /// the top of `u64`.
+    fn range() -> impl Strategy<Value = Range> {
+        let start = prop_oneof![
+            0..1_000_000u64,
+            (1..=19u32, 0..2_000u64).prop_map(|(digits, offset)| {
+                (10u64.pow(digits) + offset).saturating_sub(1_000)
+            }),
+            (1..=10u32, any::<u64>(), 0..2_000u64).prop_map(|(digits, prefix, offset)| {
+                let multiplier = 10u64.pow(digits) + 1;
+                let min_prefix = 10u64.pow(digits - 1);
+                let max_prefix = (10u64.pow(digits) - 1).min(u64::MAX / multiplier);
+                let prefix = min_prefix + prefix % (max_prefix - min_prefix + 1);
+                (prefix * multiplier).saturating_add(offset).saturating_sub(1_000)
+            }),
+            (0..1_000_000u64).prop_map(|offset| u64::MAX - offset),
+        ];
+        (start, 0..2_000u64).prop_map(|(start, width)| Range {
+            start,
+            end: start.saturating_add(width),
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn prop_sum_of_invalids_fast(range in range()) {
+            prop_assert_eq!(range.sum_of_invalids(), range.sum_of_invalids_fast());
+        }
+
+        #[test]
+        fn prop_sum_of_multi_invalids_fast(range in range()) {
+            prop_assert_eq!(range.sum_of_multi_invalids(), range.sum_of_multi_invalids_fast());
+        }
+    }
 }
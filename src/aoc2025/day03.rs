@@ -1,4 +1,6 @@
+use crate::aoclib::generate::Rng;
 use crate::aoclib::runner::Runner;
+use crate::aoclib::scaling::Complexity;
 use std::str::FromStr;
 
 #[derive(Debug, Default)]
@@ -15,7 +17,7 @@ impl BatteryPack {
     /// * `max_cells` - The number of digits (cells) to turn on in the battery pack.
     pub fn max_joltage(&self, max_cells: usize) -> u64 {
         let mut result = Vec::with_capacity(max_cells);
-        let to_remove = self.battery.len() - max_cells;
+        let to_remove = self.battery.len().saturating_sub(max_cells);
         let mut removed = 0;
 
         for &digit in &self.battery { // Dereference so we don't have to dereference in the loop.
@@ -74,6 +76,10 @@ impl Runner for AdventOfCode2025Day03 {
         (2025, 3)
     }
 
+    fn title(&self) -> Option<&'static str> {
+        Some("Lobby")
+    }
+
     fn part01(&self) -> Self::Output {
         self.battery_packs.iter().map(|bp| bp.max_joltage(2)).sum::<u64>()
     }
@@ -81,6 +87,21 @@ impl Runner for AdventOfCode2025Day03 {
     fn part02(&self) -> Self::Output {
         self.battery_packs.iter().map(|bp| bp.max_joltage(12)).sum::<u64>()
     }
+
+    fn complexity(&self, _part: u32) -> Option<Complexity> {
+        Some(Complexity::Linear)
+    }
+
+    /// `size` battery packs of 100 cells.
+    fn generate(&self, size: usize, rng: &mut Rng) -> Option<String> {
+        let packs: String = (0..size)
+            .map(|_| {
+                let cells: String = (0..100).map(|_| rng.range(1..=9).to_string()).collect();
+                cells + "\n"
+            })
+            .collect();
+        Some(packs)
+    }
 }
 
 
@@ -90,7 +111,7 @@ mod tests {
     use crate::aoclib::runner::Runner;
     use std::str::FromStr;
 
-    const TEST_INPUT: &str = include_str!("../../input/test/day03.input");
+    const TEST_INPUT: &str = include_str!("../../input/test/day03/example.input");
 
     #[test]
     fn test_battery_pack_from_str() {
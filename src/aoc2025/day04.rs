@@ -1,4 +1,6 @@
+use crate::aoclib::generate::Rng;
 use crate::aoclib::runner::Runner;
+use crate::aoclib::scaling::Complexity;
 use std::str::FromStr;
 
 #[derive(Debug, Default)]
@@ -128,7 +130,10 @@ impl FromStr for AdventOfCode2025Day04 {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let lines: Vec<&str> = s.lines().collect();
         let height = lines.len() as i32;
-        let width = lines.first().map_or(0, |l| l.len()) as i32;
+        let width = lines.first().map_or(0, |l| l.chars().count()) as i32;
+        if let Some(line) = lines.iter().find(|line| line.chars().count() != width as usize) {
+            return Err(format!("Row '{}' is not {} cells wide", line, width));
+        }
         let mut rolls = vec![false; (width * height) as usize];
         let mut active_coords = Vec::new();
 
@@ -156,6 +161,10 @@ impl Runner for AdventOfCode2025Day04 {
         (2025, 4)
     }
 
+    fn title(&self) -> Option<&'static str> {
+        Some("Printing Department")
+    }
+
     fn part01(&self) -> Self::Output {
         self.count_removable_rolls()
     }
@@ -170,6 +179,23 @@ impl Runner for AdventOfCode2025Day04 {
             &self.active_coords,
         )
     }
+
+    fn complexity(&self, _part: u32) -> Option<Complexity> {
+        Some(Complexity::Linear)
+    }
+
+    /// A `size` by `size` grid, with a roll in most cells.
+    fn generate(&self, size: usize, rng: &mut Rng) -> Option<String> {
+        let grid: String = (0..size)
+            .map(|_| {
+                let row: String = (0..size)
+                    .map(|_| if rng.chance(0.6) { '@' } else { '.' })
+                    .collect();
+                row + "\n"
+            })
+            .collect();
+        Some(grid)
+    }
 }
 
 #[cfg(test)]
@@ -177,7 +203,7 @@ pub mod tests {
     use crate::aoc2025::day04::AdventOfCode2025Day04;
     use crate::aoclib::runner::Runner;
 
-    const TEST_INPUT: &str = include_str!("../../input/test/day04.input");
+    const TEST_INPUT: &str = include_str!("../../input/test/day04/example.input");
 
     #[test]
     fn test_day04_from_str() {
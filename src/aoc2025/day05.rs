@@ -1,4 +1,6 @@
+use crate::aoclib::generate::Rng;
 use crate::aoclib::runner::Runner;
+use crate::aoclib::scaling::Complexity;
 use std::str::FromStr;
 
 #[derive(Debug, Clone)]
@@ -20,6 +22,9 @@ impl FromStr for FreshIngredientRange {
         let end = parts[1]
             .parse::<u64>()
             .map_err(|_| "Invalid end value".to_string())?;
+        if start > end {
+            return Err("Range starts after its end".to_string());
+        }
         Ok(Self { start, end })
     }
 }
@@ -78,7 +83,7 @@ impl FromStr for AdventOfCode2025Day05 {
             }
 
             if !blank_line_seen {
-                let fresh_ingredient_range = line.parse::<FreshIngredientRange>().unwrap();
+                let fresh_ingredient_range = line.parse::<FreshIngredientRange>()?;
                 fresh_ingredient_ranges.push(fresh_ingredient_range);
             } else {
                 let ingredient = line
@@ -98,12 +103,17 @@ impl FromStr for AdventOfCode2025Day05 {
 }
 
 impl Runner for AdventOfCode2025Day05 {
-    type Output = u64;
+    // A range can cover all 2^64 ids, one more than a u64 holds.
+    type Output = u128;
 
     fn name(&self) -> (u32, u32) {
         (2025, 5)
     }
 
+    fn title(&self) -> Option<&'static str> {
+        Some("Cafeteria")
+    }
+
     fn part01(&self) -> Self::Output {
         self.ingredients
             .iter()
@@ -121,7 +131,7 @@ impl Runner for AdventOfCode2025Day05 {
                     })
                     .is_ok()
             })
-            .count() as u64
+            .count() as u128
     }
 
     /// Sum up all the fresh ingredient ranges to get the total number of
@@ -129,9 +139,33 @@ impl Runner for AdventOfCode2025Day05 {
     fn part02(&self) -> Self::Output {
         self.fresh_ingredient_ranges
             .iter()
-            .map(|r| r.end - r.start + 1)
+            .map(|r| (r.end - r.start) as u128 + 1)
             .sum()
     }
+
+    fn complexity(&self, part: u32) -> Option<Complexity> {
+        match part {
+            // A binary search over the merged ranges per ingredient.
+            1 => Some(Complexity::Linearithmic),
+            _ => Some(Complexity::Linear),
+        }
+    }
+
+    /// `size` fresh ingredient ranges followed by `size` ingredient IDs.
+    fn generate(&self, size: usize, rng: &mut Rng) -> Option<String> {
+        const MAX_ID: u64 = 100_000_000_000_000;
+        let mut input = String::new();
+        for _ in 0..size {
+            let start = rng.range(1..=MAX_ID);
+            let end = start + rng.range(0..=MAX_ID / 100);
+            input += &format!("{}-{}\n", start, end);
+        }
+        input += "\n";
+        for _ in 0..size {
+            input += &format!("{}\n", rng.range(1..=MAX_ID));
+        }
+        Some(input)
+    }
 }
 
 #[cfg(test)]
@@ -139,7 +173,7 @@ mod tests {
     use crate::aoc2025::day05::AdventOfCode2025Day05;
     use crate::aoclib::runner::Runner;
 
-    const TEST_INPUT: &str = include_str!("../../input/test/day05.input");
+    const TEST_INPUT: &str = include_str!("../../input/test/day05/example.input");
 
     #[test]
     fn test_name() {
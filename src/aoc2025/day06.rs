@@ -1,4 +1,6 @@
+use crate::aoclib::generate::Rng;
 use crate::aoclib::runner::Runner;
+use crate::aoclib::scaling::Complexity;
 use std::cmp::PartialEq;
 use std::str::FromStr;
 
@@ -20,8 +22,8 @@ impl Problem {
     ///
     /// For each row in the problem's grid, it parses the sequence of digits into a single number
     /// (ignoring padding). It then applies the problem's [`Operation`] to all resulting numbers.
-    pub fn solve(&self) -> i64 {
-        let parsed_numbers: Vec<i64> = self
+    pub fn solve(&self) -> u128 {
+        let parsed_numbers: Vec<u128> = self
             .values
             .iter()
             .map(|digits| self.parse_digits_ignoring_padding(digits))
@@ -39,7 +41,7 @@ impl Problem {
     /// This treats each column of the grid as a sequence of digits forming a single number.
     /// After parsing one number per column (ignoring vertical padding), it applies the
     /// problem's [`Operation`] to the results.
-    pub fn solve_transposed(&self) -> i64 {
+    pub fn solve_transposed(&self) -> u128 {
         if self.values.is_empty() { return 0; }
 
         let num_columns = self.values[0].len();
@@ -68,17 +70,35 @@ impl Problem {
     ///
     /// # Example
     /// `[0, 0, 3, 8, 7, 0]` becomes `387`.
-    fn parse_digits_ignoring_padding(&self, digits: &[u8]) -> i64 {
+    fn parse_digits_ignoring_padding(&self, digits: &[u8]) -> u128 {
         let first = digits.iter().position(|&d| d != 0);
         let last = digits.iter().rposition(|&d| d != 0);
 
         match (first, last) {
             (Some(start), Some(end)) => digits[start..=end]
                 .iter()
-                .fold(0i64, |acc, &d| acc * 10 + d as i64),
+                .fold(0u128, |acc, &d| acc * 10 + d as u128),
             _ => 0,
         }
     }
+
+    /// An upper bound on the result of both [`Problem::solve`] and [`Problem::solve_transposed`],
+    /// or `None` if it doesn't fit in a `u128`.
+    ///
+    /// A number read across `n` cells is below `10^n`. Reading rows gives one number per row with
+    /// at most one digit per column, and reading columns gives the reverse.
+    fn bound(&self) -> Option<u128> {
+        let rows = self.values.len() as u32;
+        let columns = self.values.first().map_or(0, Vec::len) as u32;
+        match self.operation {
+            Operation::Add => {
+                let digits = rows.max(columns);
+                (digits as u128).checked_mul(10u128.checked_pow(digits)?)
+            }
+            Operation::Multiply => 10u128.checked_pow(rows.checked_mul(columns)?),
+            Operation::NoOp => Some(0),
+        }
+    }
 }
 
 #[derive(Debug, Default)]
@@ -87,7 +107,7 @@ pub struct AdventOfCode2025Day06 {
 }
 
 impl FromStr for AdventOfCode2025Day06 {
-    type Err = ();
+    type Err = String;
 
     /// Parses the input string into a series of `Problem` structs.
     ///
@@ -106,7 +126,23 @@ impl FromStr for AdventOfCode2025Day06 {
     /// 2. A 'Multiply' problem using columns 5 through the end.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let lines: Vec<&str> = s.lines().collect();
-        let last_line = lines.last().unwrap();
+        let (last_line, grid_lines) = lines
+            .split_last()
+            .ok_or_else(|| "Input is empty".to_string())?;
+
+        // Index the grid by character column. Short lines are padded, as padding reads as 0.
+        let grid: Vec<Vec<char>> = grid_lines.iter().map(|line| line.chars().collect()).collect();
+        let width = grid.iter().map(Vec::len).max().unwrap_or(0);
+        let columns = |start: usize, end: usize| -> Vec<Vec<u8>> {
+            grid.iter()
+                .map(|row| {
+                    (start..end)
+                        .map(|x| row.get(x).and_then(|c| c.to_digit(10)).unwrap_or(0) as u8)
+                        .collect()
+                })
+                .collect()
+        };
+
         let mut problems: Vec<Problem> = vec![];
         let mut start = 0;
         let mut seen_start = false;
@@ -125,24 +161,8 @@ impl FromStr for AdventOfCode2025Day06 {
                         continue;
                     }
 
-                    let problem_elements: Vec<Vec<u8>> = lines[0..lines.len() - 1]
-                        .iter()
-                        .map(|line| {
-                            line[start..idx - 1]
-                                .chars()
-                                .map(|c| {
-                                    if c.is_ascii_digit() {
-                                        c.to_digit(10).unwrap() as u8
-                                    } else {
-                                        0
-                                    }
-                                })
-                                .collect()
-                        })
-                        .collect();
-
                     let problem = Problem {
-                        values: problem_elements,
+                        values: columns(start, idx - 1),
                         operation: previous_op,
                     };
                     problems.push(problem);
@@ -158,39 +178,33 @@ impl FromStr for AdventOfCode2025Day06 {
             }
         }
 
-        let problem_elements: Vec<Vec<u8>> = lines[0..lines.len() - 1]
-            .iter()
-            .map(|line| {
-                line[start..]
-                    .chars()
-                    .map(|c| {
-                        if c.is_ascii_digit() {
-                            c.to_digit(10).unwrap() as u8
-                        } else {
-                            0
-                        }
-                    })
-                    .collect()
-            })
-            .collect();
-
         let problem = Problem {
-            values: problem_elements,
+            values: columns(start, width),
             operation: previous_op,
         };
         problems.push(problem);
 
+        // Reject worksheets whose results could overflow, so the parts can't.
+        problems
+            .iter()
+            .try_fold(0u128, |total, problem| total.checked_add(problem.bound()?))
+            .ok_or_else(|| "Worksheet results are too large for a u128".to_string())?;
+
         Ok(Self { problems })
     }
 }
 
 impl Runner for AdventOfCode2025Day06 {
-    type Output = i64;
+    type Output = u128;
 
     fn name(&self) -> (u32, u32) {
         (2025, 6)
     }
 
+    fn title(&self) -> Option<&'static str> {
+        Some("Trash Compactor")
+    }
+
     fn part01(&self) -> Self::Output {
         self.problems.iter().map(|p| p.solve()).sum()
     }
@@ -198,6 +212,36 @@ impl Runner for AdventOfCode2025Day06 {
     fn part02(&self) -> Self::Output {
         self.problems.iter().map(|p| p.solve_transposed()).sum()
     }
+
+    fn complexity(&self, _part: u32) -> Option<Complexity> {
+        Some(Complexity::Linear)
+    }
+
+    /// A worksheet of `size` problems, each with four numbers of up to four digits. Numbers are
+    /// aligned left or right within their problem's columns.
+    fn generate(&self, size: usize, rng: &mut Rng) -> Option<String> {
+        const ROWS: usize = 4;
+        let mut lines = vec![String::new(); ROWS + 1];
+        for problem in 0..size.max(1) {
+            if problem > 0 {
+                lines.iter_mut().for_each(|line| line.push(' '));
+            }
+            let width = rng.range(1..=4) as usize;
+            for line in lines.iter_mut().take(ROWS) {
+                // Zeros would read as padding, so numbers only use the digits 1 to 9.
+                let digits = rng.range(1..=width as u64) as usize;
+                let number: String = (0..digits).map(|_| rng.range(1..=9).to_string()).collect();
+                if rng.chance(0.5) {
+                    line.push_str(&format!("{:<width$}", number));
+                } else {
+                    line.push_str(&format!("{:>width$}", number));
+                }
+            }
+            let operator = rng.pick(&['+', '*']);
+            lines[ROWS].push_str(&format!("{:<width$}", operator));
+        }
+        Some(lines.join("\n") + "\n")
+    }
 }
 
 #[cfg(test)]
@@ -206,17 +250,21 @@ mod tests {
     use crate::aoclib::runner::Runner;
     use std::str::FromStr;
 
-    const TEST_INPUT: &str = include_str!("../../input/test/day06.input");
+    const TEST_INPUT: &str = include_str!("../../input/test/day06/example.input");
 
     #[test]
     fn test_name() {
-        assert_eq!((2025, 06), AdventOfCode2025Day06::default().name())
+        assert_eq!((2025, 6), AdventOfCode2025Day06::default().name())
     }
 
     #[test]
     fn test_from_str() {
         let day06 = AdventOfCode2025Day06::from_str(TEST_INPUT).unwrap();
-        assert_eq!(4, day06.problems.len())
+        assert_eq!(4, day06.problems.len());
+
+        assert!(AdventOfCode2025Day06::from_str("").is_err());
+        let huge = format!("{}\n{}\n*", "9".repeat(20), "9".repeat(20));
+        assert!(AdventOfCode2025Day06::from_str(&huge).is_err());
     }
 
     #[test]
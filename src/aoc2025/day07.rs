@@ -1,4 +1,6 @@
-use crate::aoclib::runner::Runner;
+use crate::aoclib::generate::Rng;
+use crate::aoclib::runner::{PartStatus, Runner};
+use crate::aoclib::scaling::Complexity;
 use std::str::FromStr;
 
 // #[derive(Debug)]
@@ -20,7 +22,7 @@ impl FromStr for AdventOfCode2025Day07 {
     type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut start = (0, 0);
+        let mut start = None;
         let mut splitters: Vec<(usize, usize)> = vec![];
         let mut grid_height = 0;
         let mut grid_width = 0;
@@ -28,14 +30,16 @@ impl FromStr for AdventOfCode2025Day07 {
             grid_height += 1;
             for (x, c) in line.chars().enumerate() {
                 match c {
-                    'S' => start = (x, y),
+                    'S' => start = Some((x, y)),
                     '^' => splitters.push((x, y)),
                     _ => {}
                 }
-                grid_width = x + 1;
+                grid_width = grid_width.max(x + 1);
             }
         }
 
+        let start = start.ok_or_else(|| "Missing start 'S'".to_string())?;
+
         Ok(Self {
             start,
             grid_height,
@@ -52,6 +56,17 @@ impl Runner for AdventOfCode2025Day07 {
         (2025, 7)
     }
 
+    fn title(&self) -> Option<&'static str> {
+        Some("Laboratories")
+    }
+
+    fn part_status(&self, part: u32) -> PartStatus {
+        match part {
+            2 => PartStatus::Unsolved,
+            _ => PartStatus::Solved,
+        }
+    }
+
     /// Get the number of times the tachyon beam splits on the way to the end
     /// of the tachyon manifold.
     /// 
@@ -65,8 +80,13 @@ impl Runner for AdventOfCode2025Day07 {
             if beams[splitter.0] {
                 split_count += 1;
                 beams[splitter.0] = false;
-                beams[splitter.0 - 1] = true;
-                beams[splitter.0 + 1] = true;
+                // A beam split at the edge of the manifold leaves it.
+                if let Some(left) = splitter.0.checked_sub(1) {
+                    beams[left] = true;
+                }
+                if let Some(right) = beams.get_mut(splitter.0 + 1) {
+                    *right = true;
+                }
             }
         }
 
@@ -78,6 +98,35 @@ impl Runner for AdventOfCode2025Day07 {
     fn part02(&self) -> Self::Output {
        0 //todo!("Not implemented yet.")
     }
+
+    fn complexity(&self, part: u32) -> Option<Complexity> {
+        match part {
+            1 => Some(Complexity::Linear),
+            _ => None,
+        }
+    }
+
+    /// A manifold `size` rows tall and about as wide, with the start in the middle of the top
+    /// row and splitters scattered over every other row.
+    fn generate(&self, size: usize, rng: &mut Rng) -> Option<String> {
+        let width = size.max(3) | 1;
+        let mut rows = vec![];
+        for y in 0..size.max(2) {
+            let row: String = (0..width)
+                .map(|x| {
+                    if y == 0 && x == width / 2 {
+                        'S'
+                    } else if y % 2 == 0 && y > 0 && (1..width - 1).contains(&x) && rng.chance(0.3) {
+                        '^'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect();
+            rows.push(row);
+        }
+        Some(rows.join("\n") + "\n")
+    }
 }
 
 #[cfg(test)]
@@ -85,7 +134,7 @@ mod tests {
     use crate::aoc2025::day07::AdventOfCode2025Day07;
     use crate::aoclib::runner::Runner;
 
-    const TEST_INPUT: &str = include_str!("../../input/test/day07.input");
+    const TEST_INPUT: &str = include_str!("../../input/test/day07/example.input");
 
     #[test]
     fn test_name() {
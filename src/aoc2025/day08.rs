@@ -1,4 +1,7 @@
-use crate::aoclib::runner::Runner;
+use crate::aoclib::cancel::CancelToken;
+use crate::aoclib::generate::Rng;
+use crate::aoclib::runner::{Params, Runner};
+use crate::aoclib::scaling::Complexity;
 use std::collections::HashSet;
 
 use disjoint_sets::UnionFind;
@@ -9,9 +12,14 @@ use std::str::FromStr;
 
 type JunctionBox = I64Vec3;
 
+/// Largest coordinate magnitude, so squared distances can't overflow an i64.
+const MAX_COORDINATE: i64 = 1 << 29;
+
 #[derive(Debug, Default)]
 pub struct AdventOfCode2025Day08 {
     junction_boxes: Vec<JunctionBox>,
+    /// Number of closest pairs to connect in part 1, when set by the `connections` parameter.
+    connections: Option<usize>,
 }
 
 impl FromStr for AdventOfCode2025Day08 {
@@ -28,6 +36,13 @@ impl FromStr for AdventOfCode2025Day08 {
                             .trim()
                             .parse::<i64>()
                             .map_err(|e| format!("Failed to parse '{}': {}", coord, e))
+                            .and_then(|c| {
+                                if (-MAX_COORDINATE..=MAX_COORDINATE).contains(&c) {
+                                    Ok(c)
+                                } else {
+                                    Err(format!("Coordinate '{}' is out of range", coord))
+                                }
+                            })
                     })
                     .collect_tuple::<(
                         Result<i64, String>,
@@ -38,7 +53,13 @@ impl FromStr for AdventOfCode2025Day08 {
                     .unwrap_or_else(|| Err(format!("Line '{}' does not have 3 coordinates", line)))
             })
             .collect::<Result<Vec<JunctionBox>, String>>()?;
-        Ok(AdventOfCode2025Day08 { junction_boxes })
+        if junction_boxes.is_empty() {
+            return Err("No junction boxes".to_string());
+        }
+        Ok(AdventOfCode2025Day08 {
+            junction_boxes,
+            connections: None,
+        })
     }
 }
 
@@ -50,8 +71,12 @@ impl AdventOfCode2025Day08 {
         let mut uf = UnionFind::new(total_boxes);
         let mut num_components = total_boxes;
         let mut last_joined = (0, 0);
+        let token = CancelToken::current();
 
         for &(_, idx_a, idx_b) in pairs.iter() {
+            if token.is_cancelled() {
+                break;
+            }
             if uf.find(idx_a) != uf.find(idx_b) {
                 uf.union(idx_a, idx_b);
                 last_joined = (idx_a, idx_b);
@@ -90,8 +115,28 @@ impl Runner for AdventOfCode2025Day08 {
         (2025, 8)
     }
 
+    fn title(&self) -> Option<&'static str> {
+        Some("Playground")
+    }
+
+    fn apply_params(&mut self, params: &Params) -> Result<(), String> {
+        for (key, value) in params {
+            match key.as_str() {
+                "connections" => {
+                    let connections = value
+                        .parse::<usize>()
+                        .map_err(|_| format!("Invalid connections: '{}'", value))?;
+                    self.connections = Some(connections);
+                }
+                _ => return Err(format!("Unknown puzzle parameter '{}'", key)),
+            }
+        }
+        Ok(())
+    }
+
     fn part01(&self) -> Self::Output {
-        let amount = if self.junction_boxes.len() > 20usize { 1000usize } else { 10usize };
+        let default_amount = if self.junction_boxes.len() > 20usize { 1000usize } else { 10usize };
+        let amount = self.connections.unwrap_or(default_amount);
         let circuits = self.group_circuits(amount);
 
         circuits
@@ -107,6 +152,22 @@ impl Runner for AdventOfCode2025Day08 {
 
         (self.junction_boxes[a].x * self.junction_boxes[b].x) as u64
     }
+
+    /// Both parts sort the distances of every pair of junction boxes.
+    fn complexity(&self, _part: u32) -> Option<Complexity> {
+        Some(Complexity::QuadraticLog)
+    }
+
+    /// `size` junction boxes with coordinates below 100000.
+    fn generate(&self, size: usize, rng: &mut Rng) -> Option<String> {
+        let boxes: String = (0..size.max(1))
+            .map(|_| {
+                let [x, y, z] = [(); 3].map(|_| rng.range(0..=99_999));
+                format!("{},{},{}\n", x, y, z)
+            })
+            .collect();
+        Some(boxes)
+    }
 }
 
 fn distance(a: &JunctionBox, b: &JunctionBox) -> i64 {
@@ -116,18 +177,24 @@ fn distance(a: &JunctionBox, b: &JunctionBox) -> i64 {
 
 /// Calculate all pairwise distances between junction boxes
 ///
-fn asc_pair_distances(junction_boxes: &Vec<JunctionBox>) -> Vec<(i64, usize, usize)> {
+/// Stops enumerating (and skips the sort) once the running part has been cancelled.
+fn asc_pair_distances(junction_boxes: &[JunctionBox]) -> Vec<(i64, usize, usize)> {
     let n = junction_boxes.len();
+    let token = CancelToken::current();
     let mut pairs: Vec<(i64, usize, usize)> = (0..n)
         .into_par_iter()
         .flat_map(|idx_a| {
-            (idx_a + 1..n).into_par_iter().map(move |idx_b| {
+            let end = if token.is_cancelled() { idx_a + 1 } else { n };
+            (idx_a + 1..end).into_par_iter().map(move |idx_b| {
                 let distance = distance(&junction_boxes[idx_a], &junction_boxes[idx_b]);
                 (distance, idx_a, idx_b)
             })
         })
         .collect();
 
+    if token.is_cancelled() {
+        return pairs;
+    }
     pairs.par_sort_unstable_by_key(|&(d, _, _)| d); // Parallel sort
     pairs
 }
@@ -138,7 +205,7 @@ mod tests {
     use crate::aoclib::runner::Runner;
     use std::str::FromStr;
 
-    const TEST_INPUT: &str = include_str!("../../input/test/day08.input");
+    const TEST_INPUT: &str = include_str!("../../input/test/day08/example.input");
 
     #[test]
     fn test_name() {
@@ -1,7 +1,12 @@
-use crate::aoclib::runner::Runner;
+use crate::aoclib::generate::Rng;
+use crate::aoclib::runner::{PartStatus, Runner};
+use crate::aoclib::scaling::Complexity;
 use itertools::Itertools;
 use std::str::FromStr;
 
+/// Largest coordinate magnitude, so rectangle areas can't overflow an i64.
+const MAX_COORDINATE: i64 = 1 << 30;
+
 #[derive(Debug)]
 struct RedTile {
     x: i64,
@@ -21,8 +26,15 @@ impl FromStr for AdventOfCode2025Day09 {
             let (x, y) = line.split_once(',').ok_or("Invalid input format")?;
             let x = x.parse::<i64>().map_err(|_| "Invalid x coordinate")?;
             let y = y.parse::<i64>().map_err(|_| "Invalid y coordinate")?;
+            let range = -MAX_COORDINATE..=MAX_COORDINATE;
+            if !range.contains(&x) || !range.contains(&y) {
+                return Err(format!("Coordinate out of range: {}", line));
+            }
             Ok(RedTile { x, y })
         }).collect::<Result<Vec<RedTile>, String>>()?;
+        if red_tiles.len() < 2 {
+            return Err("At least two red tiles are needed".to_string());
+        }
 
         Ok(Self { red_tiles } )
     }
@@ -35,6 +47,17 @@ impl Runner for AdventOfCode2025Day09 {
         (2025, 9)
     }
 
+    fn title(&self) -> Option<&'static str> {
+        Some("Movie Theater")
+    }
+
+    fn part_status(&self, part: u32) -> PartStatus {
+        match part {
+            2 => PartStatus::Placeholder,
+            _ => PartStatus::Solved,
+        }
+    }
+
     fn part01(&self) -> Self::Output {
         self.red_tiles.iter().tuple_combinations().map(|(a, b)| {
             ((a.x - b.x).abs() + 1) * ((a.y - b.y).abs() + 1)
@@ -44,6 +67,34 @@ impl Runner for AdventOfCode2025Day09 {
     fn part02(&self) -> Self::Output {
         24
     }
+
+    /// Part 1 compares every pair of red tiles.
+    fn complexity(&self, part: u32) -> Option<Complexity> {
+        match part {
+            1 => Some(Complexity::Quadratic),
+            _ => None,
+        }
+    }
+
+    /// A rectilinear polygon shaped like a histogram of `size` bars, listed as its `2 * size + 2`
+    /// corners in order.
+    fn generate(&self, size: usize, rng: &mut Rng) -> Option<String> {
+        let base = rng.range(0..=1_000);
+        let mut x = rng.range(0..=1_000);
+        let mut height = 0;
+        let mut corners = vec![(x, base)];
+        for _ in 0..size.max(1) {
+            let previous = height;
+            while height == previous {
+                height = base + rng.range(1..=100_000);
+            }
+            corners.push((x, height));
+            x += rng.range(1..=100);
+            corners.push((x, height));
+        }
+        corners.push((x, base));
+        Some(corners.iter().map(|(x, y)| format!("{},{}\n", x, y)).collect())
+    }
 }
 
 #[cfg(test)]
@@ -52,7 +103,7 @@ mod tests {
     use crate::aoclib::runner::Runner;
     use std::str::FromStr;
 
-    const TEST_INPUT: &str = include_str!("../../input/test/day09.input");
+    const TEST_INPUT: &str = include_str!("../../input/test/day09/example.input");
 
     #[test]
     fn test_name() {
@@ -1,3 +1,5 @@
+use crate::aoclib::runner::AocDay;
+
 pub mod day01;
 pub mod day02;
 pub mod day03;
@@ -7,3 +9,18 @@ pub mod day06;
 pub mod day09;
 pub mod day07;
 pub mod day08;
+
+/// Every solved day of 2025, in order.
+pub fn days() -> Vec<Box<dyn AocDay>> {
+    vec![
+        Box::new(day01::AdventOfCode2025Day01::default()),
+        Box::new(day02::AdventOfCode2025Day02::default()),
+        Box::new(day03::AdventOfCode2025Day03::default()),
+        Box::new(day04::AdventOfCode2025Day04::default()),
+        Box::new(day05::AdventOfCode2025Day05::default()),
+        Box::new(day06::AdventOfCode2025Day06::default()),
+        Box::new(day07::AdventOfCode2025Day07::default()),
+        Box::new(day08::AdventOfCode2025Day08::default()),
+        Box::new(day09::AdventOfCode2025Day09::default()),
+    ]
+}
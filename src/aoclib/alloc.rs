@@ -0,0 +1,155 @@
+use std::fmt;
+
+/// Heap usage of a single phase, as seen by the counting allocator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AllocStats {
+    /// Number of allocations (including reallocations) made during the phase.
+    pub allocations: u64,
+    /// Highest heap usage during the phase, relative to the heap usage when it started.
+    pub peak_bytes: usize,
+}
+
+impl fmt::Display for AllocStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocs, peak {}",
+            self.allocations,
+            format_bytes(self.peak_bytes)
+        )
+    }
+}
+
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    match unit {
+        0 => format!("{} B", bytes),
+        _ => format!("{:.1} {}", value, UNITS[unit]),
+    }
+}
+
+/// Run `f` and return its allocation statistics. These are only collected when the crate is
+/// built with the `count-allocations` feature, otherwise the statistics are always `None`.
+///
+/// The counters are process wide, so allocations made by helper threads (e.g. rayon) are
+/// included in the phase that spawned them.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    #[cfg(feature = "count-allocations")]
+    {
+        counting::measure(f)
+    }
+    #[cfg(not(feature = "count-allocations"))]
+    {
+        (f(), None)
+    }
+}
+
+#[cfg(feature = "count-allocations")]
+mod counting {
+    use super::AllocStats;
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+    static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+    static CURRENT: AtomicUsize = AtomicUsize::new(0);
+    static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+    /// Wraps the system allocator, counting allocations and tracking the peak heap size.
+    pub struct CountingAllocator;
+
+    #[global_allocator]
+    static GLOBAL: CountingAllocator = CountingAllocator;
+
+    fn record_growth(bytes: usize) {
+        let current = CURRENT.fetch_add(bytes, Ordering::Relaxed) + bytes;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = unsafe { System.alloc(layout) };
+            if !ptr.is_null() {
+                ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+                record_growth(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = unsafe { System.alloc_zeroed(layout) };
+            if !ptr.is_null() {
+                ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+                record_growth(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            unsafe { System.dealloc(ptr, layout) };
+            CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+            if !new_ptr.is_null() {
+                ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+                if new_size >= layout.size() {
+                    record_growth(new_size - layout.size());
+                } else {
+                    CURRENT.fetch_sub(layout.size() - new_size, Ordering::Relaxed);
+                }
+            }
+            new_ptr
+        }
+    }
+
+    pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+        let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+        let baseline = CURRENT.load(Ordering::Relaxed);
+        PEAK.store(baseline, Ordering::Relaxed);
+
+        let result = f();
+
+        let stats = AllocStats {
+            allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+            peak_bytes: PEAK.load(Ordering::Relaxed).saturating_sub(baseline),
+        };
+        (result, Some(stats))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoclib::alloc::{format_bytes, measure};
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!("512 B", format_bytes(512));
+        assert_eq!("1.5 KiB", format_bytes(1536));
+        assert_eq!("3.0 MiB", format_bytes(3 * 1024 * 1024));
+    }
+
+    #[test]
+    #[cfg(not(feature = "count-allocations"))]
+    fn test_measure_disabled() {
+        let (value, stats) = measure(|| vec![1u8; 1024].len());
+        assert_eq!(1024, value);
+        assert_eq!(None, stats);
+    }
+
+    #[test]
+    #[cfg(feature = "count-allocations")]
+    fn test_measure() {
+        let (value, stats) = measure(|| std::hint::black_box(vec![1u8; 4096]).len());
+        let stats = stats.unwrap();
+        assert_eq!(4096, value);
+        assert!(stats.allocations >= 1);
+        assert!(stats.peak_bytes >= 4096);
+    }
+}
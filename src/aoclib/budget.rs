@@ -0,0 +1,273 @@
+use crate::aoclib::report::DayReport;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Where the runtime budgets are set. Unlike `.aoc-config` it is committed, as the budget is a
+/// team goal.
+pub const BUDGET_PATH: &str = ".aoc-budget";
+
+/// Runtime budgets for whole years and single days, read from `key = value` lines such as
+/// `2025 = 1s` or `2025 day 08 = 250ms`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Budget {
+    pub years: BTreeMap<u32, Duration>,
+    pub days: BTreeMap<(u32, u32), Duration>,
+}
+
+impl Budget {
+    /// Load the budgets, treating a missing file as no budget at all.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        match std::fs::read_to_string(path) {
+            Ok(contents) => contents
+                .parse()
+                .map_err(|e| format!("Invalid budget file {}: {}", path.display(), e)),
+            Err(_) => Ok(Budget::default()),
+        }
+    }
+
+    /// Compare the cost of the reported days with the budgets of their years and days. Years
+    /// without any budget are left out.
+    pub fn check(&self, reports: &[DayReport]) -> Vec<BudgetCheck> {
+        let mut years: BTreeMap<u32, Vec<DayCost>> = BTreeMap::new();
+        for report in reports {
+            years.entry(report.year).or_default().push(DayCost {
+                day: report.day,
+                cost: cost(report),
+                budget: self.days.get(&(report.year, report.day)).copied(),
+            });
+        }
+
+        years
+            .into_iter()
+            .filter(|(year, days)| {
+                self.years.contains_key(year) || days.iter().any(|day| day.budget.is_some())
+            })
+            .map(|(year, mut days)| {
+                days.sort_by(|a, b| b.cost.cmp(&a.cost).then(a.day.cmp(&b.day)));
+                BudgetCheck {
+                    year,
+                    budget: self.years.get(&year).copied(),
+                    total: days.iter().map(|day| day.cost).sum(),
+                    days,
+                }
+            })
+            .collect()
+    }
+}
+
+impl FromStr for Budget {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut budget = Budget::default();
+        for line in s.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| format!("Expected 'key = value', got '{}'", line))?;
+            let limit = parse_duration(value.trim())?;
+            let number = |field: &str| {
+                field
+                    .parse::<u32>()
+                    .map_err(|_| format!("Expected 'YEAR' or 'YEAR day N', got '{}'", key.trim()))
+            };
+            match key.split_whitespace().collect::<Vec<_>>()[..] {
+                [year] => {
+                    budget.years.insert(number(year)?, limit);
+                }
+                [year, "day", day] => {
+                    budget.days.insert((number(year)?, number(day)?), limit);
+                }
+                _ => {
+                    return Err(format!(
+                        "Expected 'YEAR' or 'YEAR day N', got '{}'",
+                        key.trim()
+                    ));
+                }
+            }
+        }
+        Ok(budget)
+    }
+}
+
+/// Parse a duration such as `1.5s`, `250ms` or `800us`.
+fn parse_duration(s: &str) -> Result<Duration, String> {
+    let invalid = || format!("Invalid duration '{}', expected e.g. '1.5s' or '250ms'", s);
+    let (number, scale) = if let Some(number) = s.strip_suffix("ms") {
+        (number, 1e-3)
+    } else if let Some(number) = s.strip_suffix("us").or_else(|| s.strip_suffix("µs")) {
+        (number, 1e-6)
+    } else if let Some(number) = s.strip_suffix('s') {
+        (number, 1.0)
+    } else {
+        return Err(invalid());
+    };
+    let seconds = number.trim().parse::<f64>().map_err(|_| invalid())? * scale;
+    Duration::try_from_secs_f64(seconds).map_err(|_| invalid())
+}
+
+/// Total time a day took, over every phase that ran.
+pub fn cost(report: &DayReport) -> Duration {
+    [
+        Some(&report.parse),
+        report.part01.as_ref(),
+        report.part02.as_ref(),
+    ]
+    .into_iter()
+    .flatten()
+    .map(|phase| phase.duration)
+    .sum()
+}
+
+/// The cost of one day, with its own budget if it has one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayCost {
+    pub day: u32,
+    pub cost: Duration,
+    pub budget: Option<Duration>,
+}
+
+impl DayCost {
+    pub fn exceeded(&self) -> bool {
+        self.budget.is_some_and(|budget| self.cost > budget)
+    }
+}
+
+/// The days of one year against its budgets, most expensive day first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BudgetCheck {
+    pub year: u32,
+    pub budget: Option<Duration>,
+    pub total: Duration,
+    pub days: Vec<DayCost>,
+}
+
+impl BudgetCheck {
+    /// Whether the year or any of its days went over budget.
+    pub fn exceeded(&self) -> bool {
+        self.budget.is_some_and(|budget| self.total > budget)
+            || self.days.iter().any(DayCost::exceeded)
+    }
+
+    /// Fraction of the year's budget spent on `cost`.
+    fn share(&self, cost: Duration) -> Option<f64> {
+        let budget = self.budget.filter(|budget| !budget.is_zero())?;
+        Some(cost.as_secs_f64() / budget.as_secs_f64())
+    }
+}
+
+impl fmt::Display for BudgetCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.budget, self.share(self.total)) {
+            (Some(budget), Some(share)) => writeln!(
+                f,
+                "{} runtime: {:.2?} of {:.2?} budget ({:.1}%){}",
+                self.year,
+                self.total,
+                budget,
+                share * 100.0,
+                if self.total > budget {
+                    ", OVER BUDGET"
+                } else {
+                    ""
+                }
+            )?,
+            _ => writeln!(f, "{} runtime: {:.2?}", self.year, self.total)?,
+        }
+        for day in &self.days {
+            write!(
+                f,
+                "\tday {:02} {:>12}",
+                day.day,
+                format!("{:.2?}", day.cost)
+            )?;
+            if let Some(share) = self.share(day.cost) {
+                write!(f, " {:>6.1}%", share * 100.0)?;
+            }
+            if let Some(budget) = day.budget {
+                write!(f, "  (budget {:.2?}", budget)?;
+                write!(f, "{})", if day.exceeded() { ", OVER BUDGET" } else { "" })?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoclib::budget::{Budget, parse_duration};
+    use crate::aoclib::report::{DayReport, Outcome, PhaseReport};
+    use std::time::Duration;
+
+    fn report(day: u32, millis: [u64; 3]) -> DayReport {
+        let phase = |millis| PhaseReport {
+            outcome: Outcome::Answer("1".to_string()),
+            duration: Duration::from_millis(millis),
+            alloc: None,
+        };
+        DayReport::new(
+            2025,
+            day,
+            phase(millis[0]),
+            Some(phase(millis[1])),
+            Some(phase(millis[2])),
+        )
+    }
+
+    #[test]
+    fn test_parse() {
+        let budget: Budget = "# team goal\n2025 = 1s\n2025 day 08 = 250ms\n"
+            .parse()
+            .unwrap();
+        assert_eq!(Some(&Duration::from_secs(1)), budget.years.get(&2025));
+        assert_eq!(
+            Some(&Duration::from_millis(250)),
+            budget.days.get(&(2025, 8))
+        );
+
+        assert!("2025".parse::<Budget>().is_err());
+        assert!("2025 = 1".parse::<Budget>().is_err());
+        assert!("2025 part 1 = 1s".parse::<Budget>().is_err());
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(Ok(Duration::from_millis(1500)), parse_duration("1.5s"));
+        assert_eq!(Ok(Duration::from_micros(800)), parse_duration("800us"));
+        assert_eq!(Ok(Duration::from_micros(800)), parse_duration("800µs"));
+        assert!(parse_duration("-1s").is_err());
+        assert!(parse_duration("fast").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let budget: Budget = "2025 = 100ms\n2025 day 2 = 10ms".parse().unwrap();
+        let reports = [report(1, [1, 2, 3]), report(2, [10, 20, 30])];
+
+        let checks = budget.check(&reports);
+        assert_eq!(1, checks.len());
+        let check = &checks[0];
+        assert_eq!(Duration::from_millis(66), check.total);
+        assert_eq!(
+            vec![2, 1],
+            check.days.iter().map(|d| d.day).collect::<Vec<_>>()
+        );
+        assert!(check.days[0].exceeded());
+        assert!(check.exceeded());
+
+        let display = check.to_string();
+        assert!(display.contains("66.00ms of 100.00ms budget (66.0%)"));
+        assert!(display.contains("60.0%"));
+
+        let relaxed: Budget = "2025 = 100ms".parse().unwrap();
+        assert!(!relaxed.check(&reports)[0].exceeded());
+        assert!(Budget::default().check(&reports).is_empty());
+    }
+}
@@ -0,0 +1,236 @@
+use crate::aoclib::input;
+use crate::aoclib::report::{DayReport, Outcome, PhaseReport};
+use crate::aoclib::runner::{AocDay, Params, RunOptions};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::Duration;
+
+/// Where answers are cached. It lives under `target/` as entries are only valid for a build.
+pub const ANSWER_CACHE_PATH: &str = "target/aoc-answers.tsv";
+
+/// A short content hash identifying a puzzle input.
+pub fn fingerprint(input: &str) -> String {
+    hex_prefix(&Sha256::digest(input.as_bytes()))
+}
+
+/// A hash of the running executable, so answers computed by an older build of a solution are
+/// never reported as current. `None` if the executable can't be read, which disables caching.
+pub fn build_id() -> Option<&'static str> {
+    static BUILD_ID: OnceLock<Option<String>> = OnceLock::new();
+    BUILD_ID
+        .get_or_init(|| {
+            let exe = std::env::current_exe().ok()?;
+            let bytes = std::fs::read(exe).ok()?;
+            Some(hex_prefix(&Sha256::digest(bytes)))
+        })
+        .as_deref()
+}
+
+fn hex_prefix(digest: &[u8]) -> String {
+    digest[..8].iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// The answers of a fully solved day, for one input and one build.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachedAnswers {
+    pub year: u32,
+    pub day: u32,
+    pub fingerprint: String,
+    pub build: String,
+    pub part01: String,
+    pub part02: String,
+}
+
+impl std::str::FromStr for CachedAnswers {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = s.split('\t').collect();
+        let [year, day, fingerprint, build, part01, part02] = fields[..] else {
+            return Err(format!("Invalid answer cache line: '{}'", s));
+        };
+        let number = |field: &str| {
+            field
+                .parse::<u32>()
+                .map_err(|_| format!("Invalid answer cache line: '{}'", s))
+        };
+        Ok(CachedAnswers {
+            year: number(year)?,
+            day: number(day)?,
+            fingerprint: fingerprint.to_string(),
+            build: build.to_string(),
+            part01: part01.to_string(),
+            part02: part02.to_string(),
+        })
+    }
+}
+
+impl std::fmt::Display for CachedAnswers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\t{}\t{}\t{}",
+            self.year, self.day, self.fingerprint, self.build, self.part01, self.part02
+        )
+    }
+}
+
+/// Answers keyed by (year, day, input fingerprint, build), stored one per line.
+pub struct AnswerCache {
+    path: PathBuf,
+    entries: Vec<CachedAnswers>,
+}
+
+impl AnswerCache {
+    /// Load the cache, treating a missing or unreadable file as an empty cache.
+    pub fn load(path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref().to_path_buf();
+        let entries = std::fs::read_to_string(&path)
+            .map(|contents| contents.lines().filter_map(|l| l.parse().ok()).collect())
+            .unwrap_or_default();
+        AnswerCache { path, entries }
+    }
+
+    pub fn get(
+        &self,
+        year: u32,
+        day: u32,
+        fingerprint: &str,
+        build: &str,
+    ) -> Option<&CachedAnswers> {
+        self.entries.iter().rev().find(|e| {
+            (e.year, e.day, e.fingerprint.as_str(), e.build.as_str())
+                == (year, day, fingerprint, build)
+        })
+    }
+
+    /// Append answers to the cache file, unless the same entry is already there.
+    pub fn store(&mut self, answers: CachedAnswers) -> Result<(), String> {
+        use std::io::Write;
+
+        if self.entries.contains(&answers) {
+            return Ok(());
+        }
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+        }
+        let write_error =
+            |e: std::io::Error| format!("Failed to write {}: {}", self.path.display(), e);
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(write_error)?;
+        writeln!(file, "{}", answers).map_err(write_error)?;
+        self.entries.push(answers);
+        Ok(())
+    }
+}
+
+/// Run a day on its input, storing the answers when both parts are solved. With `use_cached`,
+/// answers already stored for the same input and build are reported without running the day.
+/// Runs of a specific variant bypass the cache.
+pub fn run_day(
+    day: &dyn AocDay,
+    options: &RunOptions,
+    cache: &mut AnswerCache,
+    use_cached: bool,
+) -> Result<DayReport, String> {
+    let Ok(Some(input)) = input::load(&day.input_path()) else {
+        // Let the runner report the missing or unreadable input.
+        return Ok(day.run_day(options));
+    };
+    let (year, day_num) = day.year_day();
+    let fingerprint = fingerprint(&input);
+    let Some(build) = build_id().filter(|_| options.variant.is_none()) else {
+        return Ok(day.run_input(&input, &Params::new(), options));
+    };
+
+    if use_cached && let Some(cached) = cache.get(year, day_num, &fingerprint, build) {
+        let answer = |answer: &str| PhaseReport {
+            outcome: Outcome::Answer(answer.to_string()),
+            duration: Duration::ZERO,
+            alloc: None,
+        };
+        let report = DayReport::new(
+            year,
+            day_num,
+            answer(""),
+            Some(answer(&cached.part01)),
+            Some(answer(&cached.part02)),
+        );
+        return Ok(DayReport {
+            cached: true,
+            ..report.with_fingerprint(fingerprint)
+        });
+    }
+
+    let report = day.run_input(&input, &Params::new(), options);
+    if let (Some(part01), Some(part02)) = (
+        report.part01.as_ref().and_then(PhaseReport::answer),
+        report.part02.as_ref().and_then(PhaseReport::answer),
+    ) {
+        // The answers are already computed, so a cache that can't be written is only a warning.
+        if let Err(e) = cache.store(CachedAnswers {
+            year,
+            day: day_num,
+            fingerprint,
+            build: build.to_string(),
+            part01: part01.to_string(),
+            part02: part02.to_string(),
+        }) {
+            eprintln!("Answers not cached: {}", e);
+        }
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoclib::cache::{AnswerCache, CachedAnswers, fingerprint};
+
+    fn answers(fingerprint: &str, build: &str, part01: &str) -> CachedAnswers {
+        CachedAnswers {
+            year: 2025,
+            day: 1,
+            fingerprint: fingerprint.to_string(),
+            build: build.to_string(),
+            part01: part01.to_string(),
+            part02: "6".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        assert_eq!(16, fingerprint("L68\n").len());
+        assert_eq!(fingerprint("L68\n"), fingerprint("L68\n"));
+        assert_ne!(fingerprint("L68\n"), fingerprint("L69\n"));
+    }
+
+    #[test]
+    fn test_store_and_get() {
+        let path = std::env::temp_dir().join(format!("aoc-cache-{}.tsv", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let mut cache = AnswerCache::load(&path);
+        cache.store(answers("aaaa", "b1", "3")).unwrap();
+        cache.store(answers("aaaa", "b1", "3")).unwrap();
+        cache.store(answers("bbbb", "b1", "4")).unwrap();
+
+        let cache = AnswerCache::load(&path);
+        assert_eq!(2, std::fs::read_to_string(&path).unwrap().lines().count());
+        assert_eq!(
+            Some("3"),
+            cache.get(2025, 1, "aaaa", "b1").map(|a| a.part01.as_str())
+        );
+        assert_eq!(
+            Some("4"),
+            cache.get(2025, 1, "bbbb", "b1").map(|a| a.part01.as_str())
+        );
+        assert_eq!(None, cache.get(2025, 1, "aaaa", "b2"));
+        assert_eq!(None, cache.get(2025, 2, "aaaa", "b1"));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,196 @@
+use crate::aoclib::runner::PartStatus;
+use std::fmt::Write;
+
+/// How far along a day of the event is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DayState {
+    /// Both parts are solved and match their accepted answers.
+    Verified,
+    /// One part is solved and matches its accepted answer.
+    OnePart,
+    /// Both parts are solved, but neither answer has been accepted yet.
+    Unverified,
+    /// A part is a placeholder or unsolved, and no part has an accepted answer.
+    Unfinished,
+    /// The day isn't registered.
+    NotStarted,
+}
+
+impl DayState {
+    /// The state of a day from the status of its parts (`None` when it isn't registered) and
+    /// whether each part has an accepted answer.
+    pub fn of(parts: Option<[PartStatus; 2]>, accepted: [bool; 2]) -> Self {
+        let Some(parts) = parts else {
+            return DayState::NotStarted;
+        };
+        let verified = parts
+            .iter()
+            .zip(accepted)
+            .filter(|&(&status, accepted)| status == PartStatus::Solved && accepted)
+            .count();
+        match verified {
+            2 => DayState::Verified,
+            1 => DayState::OnePart,
+            _ if parts.iter().all(|&status| status == PartStatus::Solved) => DayState::Unverified,
+            _ => DayState::Unfinished,
+        }
+    }
+
+    /// Marker shown next to the day number, so the states can be told apart without colour.
+    fn marker(&self) -> &'static str {
+        match self {
+            DayState::Verified => "**",
+            DayState::OnePart => "* ",
+            DayState::Unverified => "? ",
+            DayState::Unfinished => "! ",
+            DayState::NotStarted => "  ",
+        }
+    }
+
+    /// ANSI colour of the day's cell.
+    fn colour(&self) -> &'static str {
+        match self {
+            DayState::Verified => "\x1b[1;32m",
+            DayState::OnePart => "\x1b[1;33m",
+            DayState::Unverified => "\x1b[36m",
+            DayState::Unfinished => "\x1b[31m",
+            DayState::NotStarted => "\x1b[2m",
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            DayState::Verified => "both parts verified",
+            DayState::OnePart => "one part verified",
+            DayState::Unverified => "solved, not verified",
+            DayState::Unfinished => "placeholder or unsolved",
+            DayState::NotStarted => "not started",
+        }
+    }
+}
+
+/// Number of puzzles in a year's event: 25 until 2024, 12 from 2025 on.
+pub fn event_days(year: u32) -> u32 {
+    if year >= 2025 { 12 } else { 25 }
+}
+
+/// Day of the week of December 1st, from 0 for Monday to 6 for Sunday.
+pub fn first_weekday(year: u32) -> u32 {
+    // Sakamoto's method, which counts from Sunday.
+    const MONTH_OFFSETS: [u32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+    let sunday_based = (year + year / 4 - year / 100 + year / 400 + MONTH_OFFSETS[11] + 1) % 7;
+    (sunday_based + 6) % 7
+}
+
+/// A month grid of the event's days, one week per row starting on Monday. `states` holds the
+/// state of each day, starting with day 1.
+pub fn render(year: u32, states: &[DayState], colour: bool) -> String {
+    const WIDTH: usize = 6;
+    let mut grid = format!("December {}\n", year);
+    for weekday in ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"] {
+        let _ = write!(grid, "{:<WIDTH$}", weekday);
+    }
+    grid = grid.trim_end().to_string() + "\n";
+
+    let offset = first_weekday(year) as usize;
+    let mut line = " ".repeat(offset * WIDTH);
+    for (index, state) in states.iter().enumerate() {
+        let cell = format!("{:>2}{}", index + 1, state.marker());
+        if colour {
+            let _ = write!(line, "{}{}\x1b[0m  ", state.colour(), cell);
+        } else {
+            let _ = write!(line, "{:<WIDTH$}", cell);
+        }
+        if (offset + index + 1).is_multiple_of(7) {
+            grid += line.trim_end();
+            grid += "\n";
+            line.clear();
+        }
+    }
+    if !line.is_empty() {
+        grid += line.trim_end();
+        grid += "\n";
+    }
+
+    grid += "\n";
+    for state in [
+        DayState::Verified,
+        DayState::OnePart,
+        DayState::Unverified,
+        DayState::Unfinished,
+        DayState::NotStarted,
+    ] {
+        let count = states.iter().filter(|&&s| s == state).count();
+        let key = if colour {
+            format!("{}{}\x1b[0m", state.colour(), state.marker())
+        } else {
+            state.marker().to_string()
+        };
+        let _ = writeln!(grid, "{} {} ({})", key, state.description(), count);
+    }
+    grid
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoclib::calendar::{DayState, event_days, first_weekday, render};
+    use crate::aoclib::runner::PartStatus::{Placeholder, Solved, Unsolved};
+
+    #[test]
+    fn test_day_state() {
+        assert_eq!(
+            DayState::Verified,
+            DayState::of(Some([Solved, Solved]), [true, true])
+        );
+        assert_eq!(
+            DayState::OnePart,
+            DayState::of(Some([Solved, Solved]), [true, false])
+        );
+        assert_eq!(
+            DayState::OnePart,
+            DayState::of(Some([Solved, Unsolved]), [true, false])
+        );
+        assert_eq!(
+            DayState::Unverified,
+            DayState::of(Some([Solved, Solved]), [false, false])
+        );
+        assert_eq!(
+            DayState::Unfinished,
+            DayState::of(Some([Solved, Placeholder]), [false, true])
+        );
+        assert_eq!(DayState::NotStarted, DayState::of(None, [true, true]));
+    }
+
+    #[test]
+    fn test_first_weekday() {
+        assert_eq!(0, first_weekday(2025));
+        assert_eq!(6, first_weekday(2024));
+        assert_eq!(4, first_weekday(2023));
+        assert_eq!(1, first_weekday(2015));
+        assert_eq!(12, event_days(2025));
+        assert_eq!(25, event_days(2024));
+    }
+
+    #[test]
+    fn test_render() {
+        let mut states = vec![DayState::Verified; 6];
+        states.extend([
+            DayState::OnePart,
+            DayState::Unverified,
+            DayState::Unfinished,
+        ]);
+        states.extend([DayState::NotStarted; 3]);
+
+        let grid = render(2025, &states, false);
+        let lines: Vec<&str> = grid.lines().collect();
+        assert_eq!("December 2025", lines[0]);
+        assert_eq!("Mo    Tu    We    Th    Fr    Sa    Su", lines[1]);
+        assert_eq!(" 1**   2**   3**   4**   5**   6**   7*", lines[2]);
+        assert_eq!(" 8?    9!   10    11    12", lines[3]);
+        assert!(grid.contains("** both parts verified (6)"));
+        assert!(grid.contains("!  placeholder or unsolved (1)"));
+
+        let coloured = render(2025, &states, true);
+        assert!(coloured.contains("\x1b[1;32m 1**\x1b[0m"));
+    }
+}
@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+use std::fmt;
+
+/// Cache geometry given to the simulator, so counts don't depend on the machine's own caches.
+pub const CACHE_ARGS: [&str; 4] = [
+    "--cache-sim=yes",
+    "--I1=32768,8,64",
+    "--D1=32768,8,64",
+    "--LL=8388608,16,64",
+];
+
+/// Event counts of one phase, read from a callgrind output file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CallgrindStats {
+    /// Instructions executed.
+    pub instructions: u64,
+    /// Instruction and data accesses served by the first level caches.
+    pub l1_hits: u64,
+    /// Accesses that missed the first level but hit the last level cache.
+    pub ll_hits: u64,
+    /// Accesses that missed every cache level.
+    pub ram_accesses: u64,
+}
+
+impl CallgrindStats {
+    /// Read the totals of a callgrind output file, which was run with [`CACHE_ARGS`].
+    pub fn parse(output: &str) -> Result<Self, String> {
+        let mut events = None;
+        let mut totals = None;
+        for line in output.lines() {
+            if let Some(names) = line.strip_prefix("events:") {
+                events = Some(names.split_whitespace().collect::<Vec<_>>());
+            } else if let Some(counts) = line
+                .strip_prefix("summary:")
+                .or_else(|| line.strip_prefix("totals:"))
+            {
+                totals = Some(
+                    counts
+                        .split_whitespace()
+                        .map(|count| {
+                            count
+                                .parse::<u64>()
+                                .map_err(|_| format!("Invalid event count '{}'", count))
+                        })
+                        .collect::<Result<Vec<_>, _>>()?,
+                );
+            }
+        }
+
+        let events = events.ok_or("No events line in callgrind output")?;
+        let totals = totals.ok_or("No totals line in callgrind output")?;
+        let counts: HashMap<&str, u64> = events.into_iter().zip(totals).collect();
+        let count = |names: &[&str]| {
+            names
+                .iter()
+                .filter_map(|name| counts.get(name))
+                .sum::<u64>()
+        };
+
+        let instructions = count(&["Ir"]);
+        let accesses = count(&["Ir", "Dr", "Dw"]);
+        let l1_misses = count(&["I1mr", "D1mr", "D1mw"]);
+        let ram_accesses = count(&["ILmr", "DLmr", "DLmw"]);
+        Ok(CallgrindStats {
+            instructions,
+            l1_hits: accesses.saturating_sub(l1_misses),
+            ll_hits: l1_misses.saturating_sub(ram_accesses),
+            ram_accesses,
+        })
+    }
+
+    /// A rough cycle count, weighting last level hits and RAM accesses by their usual latency.
+    pub fn estimated_cycles(&self) -> u64 {
+        self.l1_hits + 5 * self.ll_hits + 35 * self.ram_accesses
+    }
+
+    /// Describe these counts, with the relative change from a previous run when there is one.
+    pub fn compare(&self, previous: Option<&CallgrindStats>) -> String {
+        let rows = [
+            (
+                "Instructions",
+                self.instructions,
+                previous.map(|p| p.instructions),
+            ),
+            ("L1 hits", self.l1_hits, previous.map(|p| p.l1_hits)),
+            ("LL hits", self.ll_hits, previous.map(|p| p.ll_hits)),
+            (
+                "RAM accesses",
+                self.ram_accesses,
+                previous.map(|p| p.ram_accesses),
+            ),
+            (
+                "Estimated cycles",
+                self.estimated_cycles(),
+                previous.map(|p| p.estimated_cycles()),
+            ),
+        ];
+        rows.iter()
+            .map(|(label, count, previous)| match previous {
+                Some(previous) => format!(
+                    "  {:<17}{:>15} ({})",
+                    label,
+                    count,
+                    change(*count, *previous)
+                ),
+                None => format!("  {:<17}{:>15}", label, count),
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl fmt::Display for CallgrindStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.compare(None))
+    }
+}
+
+fn change(count: u64, previous: u64) -> String {
+    if count == previous {
+        return "no change".to_string();
+    }
+    if previous == 0 {
+        return "new".to_string();
+    }
+    format!(
+        "{:+.2}%",
+        (count as f64 - previous as f64) / previous as f64 * 100.0
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoclib::callgrind::CallgrindStats;
+
+    const OUTPUT: &str = "\
+version: 1
+creator: callgrind-3.22.0
+cmd: aoc_instructions --aoc-phase 2025 1 parse
+events: Ir Dr Dw I1mr D1mr D1mw ILmr DLmr DLmw
+fn=(1) aoc_instructions::measured
+0 1000 300 200 10 20 5 2 3 1
+summary: 1000 300 200 10 20 5 2 3 1
+totals: 1000 300 200 10 20 5 2 3 1
+";
+
+    #[test]
+    fn test_parse() {
+        let stats = CallgrindStats::parse(OUTPUT).unwrap();
+        assert_eq!(
+            CallgrindStats {
+                instructions: 1000,
+                l1_hits: 1465,
+                ll_hits: 29,
+                ram_accesses: 6,
+            },
+            stats
+        );
+        assert_eq!(1465 + 5 * 29 + 35 * 6, stats.estimated_cycles());
+
+        assert!(CallgrindStats::parse("summary: 1").is_err());
+        assert!(CallgrindStats::parse("events: Ir\nsummary: x").is_err());
+    }
+
+    #[test]
+    fn test_parse_without_cache_sim() {
+        let stats = CallgrindStats::parse("events: Ir\ntotals: 42\n").unwrap();
+        assert_eq!(42, stats.instructions);
+        assert_eq!(42, stats.l1_hits);
+        assert_eq!(0, stats.ram_accesses);
+    }
+
+    #[test]
+    fn test_compare() {
+        let stats = CallgrindStats::parse(OUTPUT).unwrap();
+        let previous = CallgrindStats {
+            instructions: 800,
+            ..stats
+        };
+        let report = stats.compare(Some(&previous));
+        assert!(report.contains("Instructions"));
+        assert!(report.contains("(+25.00%)"));
+        assert!(report.contains("(no change)"));
+    }
+}
@@ -0,0 +1,68 @@
+use std::cell::RefCell;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// A cooperative cancellation flag shared between the runner and a running part.
+///
+/// The runner cancels the token when a part overruns its time limit. Long running solutions can
+/// poll [`CancelToken::current`] and bail out early; the runner ignores whatever they return
+/// once the deadline has passed.
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<CancelToken>> = const { RefCell::new(None) };
+}
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// The token of the part running on this thread, or a token that is never cancelled.
+    ///
+    /// The token is thread local, so solutions that fan out (e.g. with rayon) should fetch it
+    /// once and move a clone into their workers.
+    pub fn current() -> Self {
+        CURRENT.with(|current| current.borrow().clone().unwrap_or_default())
+    }
+
+    /// Run `f` with this token installed as the [`CancelToken::current`] token of the thread.
+    pub fn scope<T>(&self, f: impl FnOnce() -> T) -> T {
+        let previous = CURRENT.with(|current| current.replace(Some(self.clone())));
+        let result = f();
+        CURRENT.with(|current| *current.borrow_mut() = previous);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoclib::cancel::CancelToken;
+
+    #[test]
+    fn test_current_defaults_to_uncancelled() {
+        assert!(!CancelToken::current().is_cancelled());
+    }
+
+    #[test]
+    fn test_scope() {
+        let token = CancelToken::new();
+        token.scope(|| {
+            assert!(!CancelToken::current().is_cancelled());
+            token.cancel();
+            assert!(CancelToken::current().is_cancelled());
+        });
+        assert!(!CancelToken::current().is_cancelled());
+    }
+}
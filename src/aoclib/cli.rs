@@ -0,0 +1,80 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// Command line arguments in the form `[command] [--flag [value]]...`.
+///
+/// A flag followed by another flag (or nothing) is treated as a boolean switch.
+#[derive(Debug, Default, PartialEq)]
+pub struct Args {
+    pub command: Option<String>,
+    flags: HashMap<String, Option<String>>,
+}
+
+impl Args {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut parsed = Args::default();
+        let mut args = args.into_iter().peekable();
+
+        while let Some(arg) = args.next() {
+            match arg.strip_prefix("--") {
+                Some(name) => {
+                    let value = args.next_if(|next| !next.starts_with("--"));
+                    parsed.flags.insert(name.to_string(), value);
+                }
+                None if parsed.command.is_none() && parsed.flags.is_empty() => {
+                    parsed.command = Some(arg)
+                }
+                None => return Err(format!("Unexpected argument: '{}'", arg)),
+            }
+        }
+        Ok(parsed)
+    }
+
+    pub fn has(&self, name: &str) -> bool {
+        self.flags.contains_key(name)
+    }
+
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.flags.get(name).and_then(|value| value.as_deref())
+    }
+
+    /// Parse the value of `--name`, if it was given.
+    pub fn parse_value<T: FromStr>(&self, name: &str) -> Result<Option<T>, String> {
+        match self.flags.get(name) {
+            None => Ok(None),
+            Some(None) => Err(format!("Missing value for --{}", name)),
+            Some(Some(value)) => value
+                .parse::<T>()
+                .map(Some)
+                .map_err(|_| format!("Invalid value for --{}: '{}'", name, value)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoclib::cli::Args;
+
+    fn args(line: &str) -> Result<Args, String> {
+        Args::parse(line.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn test_parse() {
+        let parsed = args("run --day 7 --verbose --timeout 1.5").unwrap();
+        assert_eq!(Some("run".to_string()), parsed.command);
+        assert_eq!(Some(7), parsed.parse_value::<u32>("day").unwrap());
+        assert_eq!(Some(1.5), parsed.parse_value::<f64>("timeout").unwrap());
+        assert!(parsed.has("verbose"));
+        assert_eq!(None, parsed.value("verbose"));
+        assert!(!parsed.has("cached"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(args("run --day 7 extra").is_err());
+        assert!(args("--day x").unwrap().parse_value::<u32>("day").is_err());
+        assert!(args("--day").unwrap().parse_value::<u32>("day").is_err());
+        assert_eq!(None, args("").unwrap().command);
+    }
+}
@@ -0,0 +1,295 @@
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Default location of the client configuration file, overridable with `AOC_CONFIG`.
+pub const CONFIG_PATH: &str = ".aoc-config";
+
+/// Where the time of the last request is kept, so the throttle holds across `aoc` invocations.
+pub const LAST_REQUEST_PATH: &str = "target/aoc-last-request";
+
+const USER_AGENT: &str = "github.com/thomastickle/advent-of-code-rs";
+
+/// Settings for talking to the Advent of Code site.
+///
+/// Read from a `key = value` file (`session`, `base_url`, `throttle_seconds`), then overridden
+/// by the `AOC_SESSION` and `AOC_BASE_URL` environment variables.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClientConfig {
+    pub base_url: String,
+    pub session: Option<String>,
+    /// Minimum time between two requests.
+    pub throttle: Duration,
+    /// File recording the time of the last request, shared by every client using it. Without
+    /// one, only requests made by the same client are throttled.
+    pub last_request_path: Option<PathBuf>,
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        ClientConfig {
+            base_url: DEFAULT_BASE_URL.to_string(),
+            session: None,
+            throttle: Duration::from_secs(3),
+            last_request_path: Some(PathBuf::from(LAST_REQUEST_PATH)),
+        }
+    }
+}
+
+impl ClientConfig {
+    /// Load the configuration from the config file (if it exists) and the environment.
+    pub fn load() -> Result<Self, String> {
+        let path = std::env::var("AOC_CONFIG").unwrap_or_else(|_| CONFIG_PATH.to_string());
+        Self::load_from(Path::new(&path), |name| std::env::var(name).ok())
+    }
+
+    pub fn load_from(path: &Path, env: impl Fn(&str) -> Option<String>) -> Result<Self, String> {
+        let mut config = match std::fs::read_to_string(path) {
+            Ok(contents) => Self::parse(&contents)
+                .map_err(|e| format!("Invalid config file {}: {}", path.display(), e))?,
+            Err(_) => ClientConfig::default(),
+        };
+        if let Some(session) = env("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        if let Some(base_url) = env("AOC_BASE_URL") {
+            config.base_url = base_url;
+        }
+        Ok(config)
+    }
+
+    fn parse(contents: &str) -> Result<Self, String> {
+        let mut config = ClientConfig::default();
+        for line in contents.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| format!("Expected 'key = value', got '{}'", line))?;
+            let value = value.trim().to_string();
+            match key.trim() {
+                "session" => config.session = Some(value),
+                "base_url" => config.base_url = value,
+                "throttle_seconds" => {
+                    config.throttle = value
+                        .parse::<f64>()
+                        .ok()
+                        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+                        .ok_or_else(|| format!("Invalid throttle_seconds: '{}'", value))?
+                }
+                key => return Err(format!("Unknown key '{}'", key)),
+            }
+        }
+        Ok(config)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+/// A throttled HTTP client that authenticates with the configured session cookie.
+pub struct AocClient {
+    config: ClientConfig,
+    agent: ureq::Agent,
+    last_request: Option<SystemTime>,
+}
+
+impl AocClient {
+    pub fn new(config: ClientConfig) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .user_agent(USER_AGENT)
+            .build()
+            .into();
+        AocClient {
+            config,
+            agent,
+            last_request: None,
+        }
+    }
+
+    pub fn config(&self) -> &ClientConfig {
+        &self.config
+    }
+
+    pub fn get(&mut self, path: &str) -> Result<HttpResponse, String> {
+        let cookie = self.session_cookie()?;
+        let url = self.url(path);
+        self.throttle();
+        let response = self.agent.get(&url).header("Cookie", &cookie).call();
+        Self::read_response(&url, response)
+    }
+
+    pub fn post_form(&mut self, path: &str, form: &[(&str, &str)]) -> Result<HttpResponse, String> {
+        let cookie = self.session_cookie()?;
+        let url = self.url(path);
+        self.throttle();
+        let response = self
+            .agent
+            .post(&url)
+            .header("Cookie", &cookie)
+            .send_form(form.iter().copied());
+        Self::read_response(&url, response)
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.config.base_url.trim_end_matches('/'), path)
+    }
+
+    fn session_cookie(&self) -> Result<String, String> {
+        self.config
+            .session
+            .as_ref()
+            .map(|session| format!("session={}", session))
+            .ok_or_else(|| {
+                format!(
+                    "No session token: set AOC_SESSION or 'session' in {}",
+                    CONFIG_PATH
+                )
+            })
+    }
+
+    /// Wait until at least the configured throttle interval has passed since the last request,
+    /// whether this client or an earlier one recorded in the last request file made it.
+    fn throttle(&mut self) {
+        let recorded = self.config.last_request_path.as_ref().and_then(|path| {
+            let millis = std::fs::read_to_string(path).ok()?.trim().parse().ok()?;
+            Some(UNIX_EPOCH + Duration::from_millis(millis))
+        });
+        if let Some(last_request) = self.last_request.max(recorded) {
+            // A last request in the future (e.g. after a clock change) waits the full interval.
+            let elapsed = last_request.elapsed().unwrap_or_default();
+            if elapsed < self.config.throttle {
+                std::thread::sleep(self.config.throttle - elapsed);
+            }
+        }
+
+        let now = SystemTime::now();
+        self.last_request = Some(now);
+        if let Some(path) = &self.config.last_request_path {
+            let millis = now
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis();
+            // Failing to record the request only weakens the throttle for later invocations.
+            if let Some(parent) = path.parent() {
+                let _ = std::fs::create_dir_all(parent);
+            }
+            let _ = std::fs::write(path, millis.to_string());
+        }
+    }
+
+    fn read_response(
+        url: &str,
+        response: Result<ureq::http::Response<ureq::Body>, ureq::Error>,
+    ) -> Result<HttpResponse, String> {
+        let mut response = response.map_err(|e| format!("Request to {} failed: {}", url, e))?;
+        let status = response.status().as_u16();
+        let body = response
+            .body_mut()
+            .read_to_string()
+            .map_err(|e| format!("Failed to read response from {}: {}", url, e))?;
+        Ok(HttpResponse { status, body })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoclib::client::{AocClient, ClientConfig, DEFAULT_BASE_URL, LAST_REQUEST_PATH};
+    use crate::aoclib::test_server::TestServer;
+    use std::path::Path;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn test_config_file_and_env() {
+        let dir = std::env::temp_dir().join(format!("aoc-client-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config");
+        std::fs::write(&path, "# comment\nsession = abc\nthrottle_seconds = 0.5\n").unwrap();
+
+        let config = ClientConfig::load_from(&path, |_| None).unwrap();
+        assert_eq!(Some("abc".to_string()), config.session);
+        assert_eq!(DEFAULT_BASE_URL, config.base_url);
+        assert_eq!(Duration::from_millis(500), config.throttle);
+
+        let env = |name: &str| (name == "AOC_BASE_URL").then(|| "http://localhost:1".to_string());
+        let config = ClientConfig::load_from(&path, env).unwrap();
+        assert_eq!("http://localhost:1", config.base_url);
+        assert_eq!(Some("abc".to_string()), config.session);
+
+        std::fs::write(&path, "colour = blue\n").unwrap();
+        assert!(ClientConfig::load_from(&path, |_| None).is_err());
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let missing = ClientConfig::load_from(Path::new("missing"), |_| None).unwrap();
+        assert_eq!(ClientConfig::default(), missing);
+        assert_eq!(
+            Some(Path::new(LAST_REQUEST_PATH)),
+            missing.last_request_path.as_deref()
+        );
+    }
+
+    #[test]
+    fn test_requests_send_session_and_throttle() {
+        let server =
+            TestServer::start(|request| (200, format!("{} {}", request.method, request.path)));
+        let mut client = AocClient::new(ClientConfig {
+            base_url: server.url(),
+            session: Some("abc".to_string()),
+            throttle: Duration::from_millis(200),
+            last_request_path: None,
+        });
+
+        let start = Instant::now();
+        assert_eq!(
+            "GET /2025/day/1/input",
+            client.get("/2025/day/1/input").unwrap().body
+        );
+        let response = client.post_form("/2025/day/1/answer", &[("level", "1"), ("answer", "3")]);
+        assert_eq!("POST /2025/day/1/answer", response.unwrap().body);
+        assert!(start.elapsed() >= Duration::from_millis(200));
+
+        let requests = server.requests();
+        assert_eq!(Some("session=abc"), requests[0].header("cookie"));
+        assert_eq!("level=1&answer=3", requests[1].body);
+    }
+
+    #[test]
+    fn test_missing_session() {
+        let mut client = AocClient::new(ClientConfig {
+            base_url: "http://127.0.0.1:1".to_string(),
+            ..ClientConfig::default()
+        });
+        assert!(client.get("/").unwrap_err().contains("AOC_SESSION"));
+    }
+
+    #[test]
+    fn test_throttle_across_clients() {
+        let server = TestServer::start(|_| (200, String::new()));
+        let dir = std::env::temp_dir().join(format!("aoc-throttle-{}", std::process::id()));
+        let config = ClientConfig {
+            base_url: server.url(),
+            session: Some("abc".to_string()),
+            throttle: Duration::from_millis(300),
+            last_request_path: Some(dir.join("last-request")),
+        };
+
+        // Each client stands in for a separate `aoc fetch` invocation.
+        let start = Instant::now();
+        AocClient::new(config.clone()).get("/1").unwrap();
+        assert!(start.elapsed() < Duration::from_millis(300));
+        AocClient::new(config.clone()).get("/2").unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(300));
+
+        let recorded = std::fs::read_to_string(dir.join("last-request")).unwrap();
+        assert!(recorded.parse::<u128>().is_ok());
+        assert_eq!(2, server.requests().len());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,158 @@
+use crate::aoclib::examples;
+use crate::aoclib::input;
+use crate::aoclib::runner::{AocDay, Params, PartStatus, RunOptions, VariantRun};
+
+/// The result of running every variant of a day on one input.
+pub struct CrossCheck {
+    /// `input` for the real puzzle input, otherwise the example name.
+    pub label: String,
+    /// Disagreements and failures between variants, empty when they all agree.
+    pub problems: Vec<String>,
+}
+
+/// Whether any solved part of `day` has more than one variant to compare.
+pub fn has_variants(day: &dyn AocDay) -> bool {
+    [1, 2].into_iter().any(|part| {
+        day.part_status(part) == PartStatus::Solved && day.variant_names(part).len() > 1
+    })
+}
+
+/// Run every variant of `day` on its real input, when there is one, and on each example.
+pub fn crosscheck(day: &dyn AocDay, options: &RunOptions) -> Result<Vec<CrossCheck>, String> {
+    let mut inputs = vec![];
+    if let Some(input) = input::load(&day.input_path())? {
+        inputs.push(("input".to_string(), input, Params::new()));
+    }
+    for example in examples::load(day.examples_dir())? {
+        let input = std::fs::read_to_string(&example.input_path)
+            .map_err(|e| format!("Failed to read {}: {}", example.input_path.display(), e))?;
+        inputs.push((example.name, input, example.expected.params));
+    }
+
+    Ok(inputs
+        .into_iter()
+        .map(|(label, input, params)| {
+            let problems = match day.run_variants(&input, &params, options) {
+                Ok(runs) => problems(day, &runs),
+                Err(parse) => vec![format!("Parse: {}", parse)],
+            };
+            CrossCheck { label, problems }
+        })
+        .collect())
+}
+
+/// Describe every failed variant, and every part whose variants disagree. Parts that are not
+/// solved, or have a single variant, are not compared.
+fn problems(day: &dyn AocDay, runs: &[VariantRun]) -> Vec<String> {
+    let mut problems = vec![];
+    for (part, runs) in compared(day, runs) {
+        for run in &runs {
+            if !run.report.is_ok() {
+                problems.push(format!("Part {:02} {}: {}", part, run.variant, run.report));
+            }
+        }
+        if let Some(answers) = disagreement(&runs) {
+            let answers: Vec<String> = answers
+                .iter()
+                .map(|(variant, answer)| format!("{} = {}", variant, answer))
+                .collect();
+            problems.push(format!(
+                "Part {:02} variants disagree: {}",
+                part,
+                answers.join(", ")
+            ));
+        }
+    }
+    problems
+}
+
+/// Whether the variants of any compared part of `day` gave different answers.
+pub fn disagrees(day: &dyn AocDay, runs: &[VariantRun]) -> bool {
+    compared(day, runs).any(|(_, runs)| disagreement(&runs).is_some())
+}
+
+/// The runs of each part that is solved and has more than one variant.
+fn compared<'a>(
+    day: &dyn AocDay,
+    runs: &'a [VariantRun],
+) -> impl Iterator<Item = (u32, Vec<&'a VariantRun>)> {
+    let solved: Vec<u32> = [1, 2]
+        .into_iter()
+        .filter(|&part| day.part_status(part) == PartStatus::Solved)
+        .collect();
+    solved.into_iter().filter_map(move |part| {
+        let runs: Vec<&VariantRun> = runs.iter().filter(|run| run.part == part).collect();
+        (runs.len() > 1).then_some((part, runs))
+    })
+}
+
+/// The answer of each variant, when they don't all agree. Failed variants are left out.
+fn disagreement<'a>(runs: &[&'a VariantRun]) -> Option<Vec<(&'static str, &'a str)>> {
+    let answers: Vec<(&'static str, &str)> = runs
+        .iter()
+        .filter_map(|run| Some((run.variant, run.report.answer()?)))
+        .collect();
+    answers
+        .iter()
+        .any(|(_, answer)| *answer != answers[0].1)
+        .then_some(answers)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoclib::crosscheck::{crosscheck, has_variants, problems};
+    use crate::aoclib::report::{Outcome, PhaseReport};
+    use crate::aoclib::runner::{RunOptions, VariantRun};
+    use crate::registry;
+    use std::time::Duration;
+
+    fn run(part: u32, variant: &'static str, answer: &str) -> VariantRun {
+        VariantRun {
+            part,
+            variant,
+            report: PhaseReport {
+                outcome: Outcome::Answer(answer.to_string()),
+                duration: Duration::ZERO,
+                alloc: None,
+            },
+        }
+    }
+
+    #[test]
+    fn test_problems() {
+        let day = registry::find(Some(2025), 2).unwrap();
+        assert!(has_variants(day.as_ref()));
+        assert!(!has_variants(
+            registry::find(Some(2025), 1).unwrap().as_ref()
+        ));
+
+        let agreeing = [
+            run(1, "fast", "33"),
+            run(1, "slow", "33"),
+            run(2, "fast", "1"),
+        ];
+        assert!(problems(day.as_ref(), &agreeing).is_empty());
+
+        let disagreeing = [run(1, "fast", "33"), run(1, "slow", "34")];
+        assert_eq!(
+            vec!["Part 01 variants disagree: fast = 33, slow = 34".to_string()],
+            problems(day.as_ref(), &disagreeing)
+        );
+    }
+
+    #[test]
+    fn test_crosscheck_examples() {
+        let day = registry::find(Some(2025), 2).unwrap();
+        let checks = crosscheck(day.as_ref(), &RunOptions::default()).unwrap();
+
+        assert!(checks.iter().any(|check| check.label == "example"));
+        for check in checks {
+            assert!(
+                check.problems.is_empty(),
+                "{}: {:?}",
+                check.label,
+                check.problems
+            );
+        }
+    }
+}
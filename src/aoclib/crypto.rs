@@ -0,0 +1,147 @@
+use chacha20poly1305::aead::{Aead, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use sha2::{Digest, Sha256};
+use std::fmt::Write;
+
+/// Environment variable holding the key for encrypted puzzle inputs, as 64 hex digits.
+pub const KEY_VAR: &str = "AOC_INPUT_KEY";
+
+const MAGIC: &[u8] = b"AOCENC1\n";
+const NONCE_LEN: usize = 12;
+
+/// The path of the encrypted copy of an input file.
+pub fn encrypted_path(path: &str) -> String {
+    format!("{}.enc", path)
+}
+
+/// A random ChaCha20-Poly1305 key shared by the team. Passphrases aren't accepted: the
+/// ciphertext is public, so a guessable passphrase could be brute forced offline.
+///
+/// Encrypted files are `MAGIC || nonce || ciphertext`. The nonce is derived from the key and the
+/// plaintext, so re-encrypting an unchanged input produces an identical file and no spurious
+/// diff, at the cost of revealing when two inputs are equal.
+pub struct InputKey {
+    key: Key,
+}
+
+impl InputKey {
+    /// The key from [`KEY_VAR`], or `None` when it isn't set.
+    pub fn from_env() -> Result<Option<Self>, String> {
+        match std::env::var(KEY_VAR) {
+            Ok(hex) if !hex.is_empty() => Self::from_hex(&hex)
+                .map(Some)
+                .map_err(|e| format!("Invalid {}: {}", KEY_VAR, e)),
+            _ => Ok(None),
+        }
+    }
+
+    /// A fresh key from the operating system's random number generator.
+    pub fn generate() -> Self {
+        InputKey {
+            key: ChaCha20Poly1305::generate_key(&mut OsRng),
+        }
+    }
+
+    /// Parse a key written as 64 hex digits, as printed by [`InputKey::to_hex`].
+    pub fn from_hex(hex: &str) -> Result<Self, String> {
+        let hex = hex.trim();
+        let invalid = || "expected a key of 64 hex digits".to_string();
+        if hex.len() != 64 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(invalid());
+        }
+        let mut key = Key::default();
+        for (byte, pair) in key.iter_mut().zip(hex.as_bytes().chunks(2)) {
+            let pair = std::str::from_utf8(pair).map_err(|_| invalid())?;
+            *byte = u8::from_str_radix(pair, 16).map_err(|_| invalid())?;
+        }
+        Ok(InputKey { key })
+    }
+
+    pub fn to_hex(&self) -> String {
+        self.key.iter().fold(String::new(), |mut hex, byte| {
+            let _ = write!(hex, "{:02x}", byte);
+            hex
+        })
+    }
+
+    pub fn encrypt(&self, plaintext: &[u8]) -> Vec<u8> {
+        let digest = Sha256::new()
+            .chain_update(self.key)
+            .chain_update(plaintext)
+            .finalize();
+        let nonce = Nonce::from_slice(&digest[..NONCE_LEN]);
+        let ciphertext = ChaCha20Poly1305::new(&self.key)
+            .encrypt(nonce, plaintext)
+            .expect("ChaCha20-Poly1305 encryption of an in-memory buffer");
+
+        let mut encrypted = Vec::with_capacity(MAGIC.len() + NONCE_LEN + ciphertext.len());
+        encrypted.extend_from_slice(MAGIC);
+        encrypted.extend_from_slice(nonce);
+        encrypted.extend_from_slice(&ciphertext);
+        encrypted
+    }
+
+    pub fn decrypt(&self, encrypted: &[u8]) -> Result<Vec<u8>, String> {
+        let data = encrypted
+            .strip_prefix(MAGIC)
+            .ok_or_else(|| "Not an encrypted input".to_string())?;
+        if data.len() < NONCE_LEN {
+            return Err("Encrypted input is truncated".to_string());
+        }
+        let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+        ChaCha20Poly1305::new(&self.key)
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| format!("Failed to decrypt input, is {} correct?", KEY_VAR))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoclib::crypto::InputKey;
+
+    const KEY: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+
+    #[test]
+    fn test_hex_key() {
+        assert_eq!(KEY, InputKey::from_hex(KEY).unwrap().to_hex());
+        assert_eq!(
+            KEY,
+            InputKey::from_hex(&KEY.to_uppercase()).unwrap().to_hex()
+        );
+        assert!(InputKey::from_hex("correct horse battery staple").is_err());
+        assert!(InputKey::from_hex(&KEY[..62]).is_err());
+        assert!(InputKey::from_hex(&KEY.replace('0', "g")).is_err());
+        assert!(InputKey::from_hex(&format!("{}é", &KEY[..62])).is_err());
+        assert!(InputKey::from_hex(&format!("+f{}", &KEY[2..])).is_err());
+
+        let generated = InputKey::generate().to_hex();
+        assert_eq!(64, generated.len());
+        assert_ne!(generated, InputKey::generate().to_hex());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let key = InputKey::from_hex(KEY).unwrap();
+        let encrypted = key.encrypt(b"L68\nL30\n");
+
+        assert!(encrypted.starts_with(b"AOCENC1\n"));
+        assert!(!encrypted.windows(3).any(|w| w == b"L68"));
+        assert_eq!(b"L68\nL30\n".to_vec(), key.decrypt(&encrypted).unwrap());
+        assert_eq!(encrypted, key.encrypt(b"L68\nL30\n"));
+    }
+
+    #[test]
+    fn test_decrypt_failures() {
+        let key = InputKey::from_hex(KEY).unwrap();
+        let mut encrypted = key.encrypt(b"L68\nL30\n");
+
+        let wrong_key = InputKey::generate();
+        assert!(wrong_key.decrypt(&encrypted).is_err());
+        assert!(key.decrypt(b"L68\nL30\n").is_err());
+        assert!(key.decrypt(b"AOCENC1\nshort").is_err());
+
+        let last = encrypted.len() - 1;
+        encrypted[last] ^= 1;
+        assert!(key.decrypt(&encrypted).is_err());
+    }
+}
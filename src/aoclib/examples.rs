@@ -0,0 +1,165 @@
+use crate::aoclib::report::DayReport;
+use crate::aoclib::runner::{AocDay, Params, RunOptions};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Expected answers for an example, read from a `key = value` file. `part01` and `part02` hold
+/// the answers and any other key is a puzzle parameter passed to [`AocDay::run_input`].
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Expected {
+    pub part01: Option<String>,
+    pub part02: Option<String>,
+    pub params: Params,
+}
+
+impl Expected {
+    pub fn part(&self, part: u32) -> Option<&str> {
+        match part {
+            1 => self.part01.as_deref(),
+            2 => self.part02.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Expected {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut expected = Expected::default();
+        for line in s.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| format!("Expected 'key = value', got '{}'", line))?;
+            let (key, value) = (key.trim(), value.trim().to_string());
+            match key {
+                "part01" => expected.part01 = Some(value),
+                "part02" => expected.part02 = Some(value),
+                _ => {
+                    expected.params.insert(key.to_string(), value);
+                }
+            }
+        }
+        Ok(expected)
+    }
+}
+
+/// An example input with its expected answers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Example {
+    pub name: String,
+    pub input_path: PathBuf,
+    pub expected: Expected,
+}
+
+impl Example {
+    /// Run a day on this example with the example's puzzle parameters.
+    pub fn run(&self, day: &dyn AocDay, options: &RunOptions) -> Result<DayReport, String> {
+        let input = std::fs::read_to_string(&self.input_path)
+            .map_err(|e| format!("Failed to read {}: {}", self.input_path.display(), e))?;
+        Ok(day.run_input(&input, &self.expected.params, options))
+    }
+
+    /// Compare a part's answer in `report` with the expected answer, if there is one.
+    pub fn verify(&self, report: &DayReport, part: u32) -> Result<(), String> {
+        let Some(expected) = self.expected.part(part) else {
+            return Ok(());
+        };
+        let phase = match part {
+            1 => report.part01.as_ref(),
+            _ => report.part02.as_ref(),
+        };
+        match phase {
+            Some(phase) if phase.answer() == Some(expected) => Ok(()),
+            Some(phase) => Err(format!("expected {}, got {}", expected, phase)),
+            None => Err(format!(
+                "expected {}, but parsing failed: {}",
+                expected, report.parse
+            )),
+        }
+    }
+}
+
+/// Load every example in `dir`: each `<name>.input` that has a matching `<name>.expected`.
+/// A missing directory has no examples.
+pub fn load(dir: impl AsRef<Path>) -> Result<Vec<Example>, String> {
+    let dir = dir.as_ref();
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Ok(vec![]);
+    };
+
+    let mut examples = vec![];
+    for entry in entries {
+        let input_path = entry.map_err(|e| e.to_string())?.path();
+        if input_path
+            .extension()
+            .is_none_or(|extension| extension != "input")
+        {
+            continue;
+        }
+        let expected_path = input_path.with_extension("expected");
+        let Ok(contents) = std::fs::read_to_string(&expected_path) else {
+            continue;
+        };
+        let expected = contents
+            .parse::<Expected>()
+            .map_err(|e| format!("Invalid {}: {}", expected_path.display(), e))?;
+        let name = input_path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default();
+        examples.push(Example {
+            name,
+            input_path,
+            expected,
+        });
+    }
+    examples.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(examples)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoclib::examples::{self, Expected};
+    use crate::aoclib::runner::RunOptions;
+    use crate::registry;
+
+    #[test]
+    fn test_expected_from_str() {
+        let expected = "# Day 08\npart01 = 40\n\nconnections = 10\n"
+            .parse::<Expected>()
+            .unwrap();
+        assert_eq!(Some("40"), expected.part(1));
+        assert_eq!(None, expected.part(2));
+        assert_eq!(Some(&"10".to_string()), expected.params.get("connections"));
+        assert!("part01: 40".parse::<Expected>().is_err());
+    }
+
+    #[test]
+    fn test_load_and_verify() {
+        let day = registry::find(Some(2025), 8).unwrap();
+        let examples = examples::load(day.examples_dir()).unwrap();
+        let example = examples.iter().find(|e| e.name == "example").unwrap();
+
+        let report = example.run(day.as_ref(), &RunOptions::default()).unwrap();
+        assert_eq!(Ok(()), example.verify(&report, 1));
+        assert_eq!(Ok(()), example.verify(&report, 2));
+
+        let mut wrong = example.clone();
+        wrong.expected.part01 = Some("41".to_string());
+        assert!(
+            wrong
+                .verify(&report, 1)
+                .unwrap_err()
+                .starts_with("expected 41, got 40")
+        );
+    }
+
+    #[test]
+    fn test_load_missing_dir() {
+        assert_eq!(Ok(vec![]), examples::load("input/test/missing"));
+    }
+}
@@ -0,0 +1,215 @@
+use std::path::{Path, PathBuf};
+
+/// Example data pulled out of a saved puzzle page.
+#[derive(Debug, Default, PartialEq)]
+pub struct PuzzlePage {
+    /// The puzzle title, e.g. `Secret Entrance` from `--- Day 1: Secret Entrance ---`.
+    pub title: Option<String>,
+    /// The contents of every `<pre><code>` block, in page order.
+    pub examples: Vec<String>,
+    /// The last emphasised code value of each part's description, which is the example answer
+    /// on almost every puzzle page.
+    pub answers: Vec<String>,
+}
+
+/// Extract the title, example blocks and example answers from a puzzle page's HTML.
+pub fn parse_page(html: &str) -> PuzzlePage {
+    let title = between(html, "<h2>", "</h2>").map(|heading| {
+        let heading = decode_entities(heading);
+        let heading = heading.trim_matches(|c: char| c == '-' || c.is_whitespace());
+        heading
+            .split_once(':')
+            .map_or(heading, |(_, title)| title)
+            .trim()
+            .to_string()
+    });
+
+    let examples = all_between(html, "<pre><code>", "</code></pre>")
+        .into_iter()
+        .map(|block| decode_entities(&strip_tags(block)))
+        .collect();
+
+    let answers = all_between(html, "<article", "</article>")
+        .into_iter()
+        .filter_map(|article| {
+            let mut emphasised: Vec<(usize, &str)> = vec![];
+            for (open, close) in [
+                ("<code><em>", "</em></code>"),
+                ("<em><code>", "</code></em>"),
+            ] {
+                let mut offset = 0;
+                while let Some((start, value)) = find_between(&article[offset..], open, close) {
+                    emphasised.push((offset + start, value));
+                    offset += start + open.len() + value.len() + close.len();
+                }
+            }
+            emphasised
+                .into_iter()
+                .max_by_key(|&(position, _)| position)
+                .map(|(_, value)| decode_entities(&strip_tags(value)))
+        })
+        .collect();
+
+    PuzzlePage {
+        title,
+        examples,
+        answers,
+    }
+}
+
+/// Write each example block to `dir/example-K.input`, plus `dir/example-1.expected` holding
+/// the part answers for the first block. Existing files are kept unless `force` is set.
+///
+/// These are candidates: check that the answers really belong to the first example before
+/// relying on them.
+pub fn write_candidates(
+    page: &PuzzlePage,
+    dir: &Path,
+    force: bool,
+) -> Result<Vec<PathBuf>, String> {
+    std::fs::create_dir_all(dir)
+        .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+
+    let mut files: Vec<(PathBuf, String)> = page
+        .examples
+        .iter()
+        .enumerate()
+        .map(|(idx, example)| {
+            let mut contents = example.clone();
+            if !contents.ends_with('\n') {
+                contents.push('\n');
+            }
+            (dir.join(format!("example-{}.input", idx + 1)), contents)
+        })
+        .collect();
+
+    if !page.examples.is_empty() && !page.answers.is_empty() {
+        let expected: String = page
+            .answers
+            .iter()
+            .enumerate()
+            .map(|(idx, answer)| format!("part{:02} = {}\n", idx + 1, answer))
+            .collect();
+        files.push((dir.join("example-1.expected"), expected));
+    }
+
+    let mut written = vec![];
+    for (path, contents) in files {
+        if path.exists() && !force {
+            continue;
+        }
+        std::fs::write(&path, contents)
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+fn find_between<'a>(haystack: &'a str, open: &str, close: &str) -> Option<(usize, &'a str)> {
+    let start = haystack.find(open)?;
+    let content = &haystack[start + open.len()..];
+    let end = content.find(close)?;
+    Some((start, &content[..end]))
+}
+
+fn between<'a>(haystack: &'a str, open: &str, close: &str) -> Option<&'a str> {
+    find_between(haystack, open, close).map(|(_, content)| content)
+}
+
+fn all_between<'a>(haystack: &'a str, open: &str, close: &str) -> Vec<&'a str> {
+    let mut found = vec![];
+    let mut rest = haystack;
+    while let Some((start, content)) = find_between(rest, open, close) {
+        found.push(content);
+        rest = &rest[start + open.len() + content.len() + close.len()..];
+    }
+    found
+}
+
+/// Remove markup such as the `<em>` highlighting inside example blocks.
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoclib::extract::{PuzzlePage, parse_page, write_candidates};
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 2: Gift Shop ---</h2>
+<p>For example:</p>
+<pre><code>11-22,95-115,<em>998-1012</em>
+</code></pre>
+<p>Any ID which is made only of some sequence of digits repeated twice is <em>invalid</em>.</p>
+<p>Adding up all the invalid IDs in this example produces <code><em>1227775554</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>12345</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Now, an ID is invalid if it is made of <code>&lt;seq&gt;</code> repeated at least twice.</p>
+<pre><code>1111 &amp; 2222</code></pre>
+<p>Adding up all the invalid IDs produces <em><code>4174379265</code></em>.</p>
+</article>
+</main></body></html>"#;
+
+    #[test]
+    fn test_parse_page() {
+        let page = parse_page(PAGE);
+        assert_eq!(Some("Gift Shop".to_string()), page.title);
+        assert_eq!(
+            vec![
+                "11-22,95-115,998-1012\n".to_string(),
+                "1111 & 2222".to_string()
+            ],
+            page.examples
+        );
+        assert_eq!(
+            vec!["1227775554".to_string(), "4174379265".to_string()],
+            page.answers
+        );
+    }
+
+    #[test]
+    fn test_parse_page_without_examples() {
+        assert_eq!(PuzzlePage::default(), parse_page("<html></html>"));
+    }
+
+    #[test]
+    fn test_write_candidates() {
+        let dir = std::env::temp_dir().join(format!("aoc-extract-{}", std::process::id()));
+        let page = parse_page(PAGE);
+
+        let written = write_candidates(&page, &dir, false).unwrap();
+        assert_eq!(3, written.len());
+        assert_eq!(
+            "part01 = 1227775554\npart02 = 4174379265\n",
+            std::fs::read_to_string(dir.join("example-1.expected")).unwrap()
+        );
+        assert_eq!(
+            "1111 & 2222\n",
+            std::fs::read_to_string(dir.join("example-2.input")).unwrap()
+        );
+
+        assert!(write_candidates(&page, &dir, false).unwrap().is_empty());
+        assert_eq!(3, write_candidates(&page, &dir, true).unwrap().len());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
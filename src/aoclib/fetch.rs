@@ -0,0 +1,121 @@
+use crate::aoclib::client::AocClient;
+use crate::aoclib::input;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Fetched {
+    /// The input was already on disk, so nothing was downloaded.
+    Cached,
+    Downloaded,
+}
+
+/// Download the puzzle input for a day.
+pub fn fetch_input(client: &mut AocClient, year: u32, day: u32) -> Result<String, String> {
+    let response = client.get(&format!("/{}/day/{}/input", year, day))?;
+    match response.status {
+        200 => Ok(response.body),
+        404 => Err(format!(
+            "Input for {} day {} is not available yet",
+            year, day
+        )),
+        400 | 500 => Err(format!(
+            "Server rejected the request for {} day {} ({}), is the session token valid?",
+            year, day, response.status
+        )),
+        status => Err(format!(
+            "Unexpected status {} fetching {} day {}: {}",
+            status,
+            year,
+            day,
+            response.body.trim()
+        )),
+    }
+}
+
+/// Where a day's puzzle input is kept, in the input dir of its year.
+pub fn input_path(year: u32, day: u32) -> String {
+    format!("input/problem/{}/day{:02}.input", year, day)
+}
+
+/// Download a day's input to `input_path`, unless it is already there.
+pub fn fetch_cached(
+    client: &mut AocClient,
+    year: u32,
+    day: u32,
+    input_path: &str,
+    force: bool,
+) -> Result<Fetched, String> {
+    let path = Path::new(input_path);
+    if input::exists(input_path) && !force {
+        return Ok(Fetched::Cached);
+    }
+
+    let input = fetch_input(client, year, day)?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+    std::fs::write(path, input).map_err(|e| format!("Failed to write {}: {}", input_path, e))?;
+    Ok(Fetched::Downloaded)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoclib::client::{AocClient, ClientConfig};
+    use crate::aoclib::fetch::{Fetched, fetch_cached, fetch_input, input_path};
+    use crate::aoclib::test_server::TestServer;
+    use std::time::Duration;
+
+    fn client(server: &TestServer) -> AocClient {
+        AocClient::new(ClientConfig {
+            base_url: server.url(),
+            session: Some("abc".to_string()),
+            throttle: Duration::ZERO,
+            last_request_path: None,
+        })
+    }
+
+    #[test]
+    fn test_input_path() {
+        assert_eq!("input/problem/2025/day03.input", input_path(2025, 3));
+        assert_eq!("input/problem/2024/day25.input", input_path(2024, 25));
+    }
+
+    #[test]
+    fn test_fetch_input_statuses() {
+        let server = TestServer::start(|request| match request.path.as_str() {
+            "/2025/day/1/input" => (200, "L68\nL30\n".to_string()),
+            "/2025/day/2/input" => (400, "Please log in".to_string()),
+            _ => (404, "Not Found".to_string()),
+        });
+        let mut client = client(&server);
+
+        assert_eq!("L68\nL30\n", fetch_input(&mut client, 2025, 1).unwrap());
+        assert!(
+            fetch_input(&mut client, 2025, 2)
+                .unwrap_err()
+                .contains("session")
+        );
+        assert!(
+            fetch_input(&mut client, 2025, 25)
+                .unwrap_err()
+                .contains("not available")
+        );
+    }
+
+    #[test]
+    fn test_fetch_cached() {
+        let server = TestServer::start(|_| (200, "987654321111111\n".to_string()));
+        let mut client = client(&server);
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+        let path = dir.join("problem/2025/day03.input").display().to_string();
+        let mut fetch = |force| fetch_cached(&mut client, 2025, 3, &path, force);
+
+        assert_eq!(Ok(Fetched::Downloaded), fetch(false));
+        assert_eq!(Ok(Fetched::Cached), fetch(false));
+        assert_eq!(Ok(Fetched::Downloaded), fetch(true));
+        assert_eq!("987654321111111\n", std::fs::read_to_string(&path).unwrap());
+        assert_eq!(2, server.requests().len());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
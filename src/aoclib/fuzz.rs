@@ -0,0 +1,66 @@
+use crate::registry;
+
+/// Feed arbitrary bytes to a registered day: parse them and, when the parser accepts them, solve
+/// both parts. Panics are not caught, so a fuzzer reports them as crashes.
+pub fn fuzz_day(year: u32, day: u32, data: &[u8]) {
+    let day = registry::find(Some(year), day).expect("registered day");
+    if let Ok(input) = std::str::from_utf8(data) {
+        let _ = day.solve(input);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoclib::fuzz::fuzz_day;
+    use crate::registry;
+
+    /// Malformed inputs that used to panic a parser or a part, kept as regression cases.
+    const MALFORMED: &[&str] = &[
+        "",
+        "\n",
+        "\n\n",
+        " ",
+        "L",
+        "é",
+        "é12",
+        "R-2147483648",
+        "L2147483647\nL2147483647",
+        "1-",
+        "x-1",
+        "3-x\n\n5",
+        "1-2\n\nx",
+        "+",
+        "*\n",
+        "1 2\n+ *",
+        "12\né4\n+  *",
+        "123\n+  *",
+        "99999999999999999999\n99\n* +",
+        "1",
+        "9",
+        "S",
+        "^",
+        ".S.\n^^^",
+        "@",
+        ".@\n@",
+        "1,2",
+        "1,2,3\n1,2",
+        "1,2,3\n1,2,x",
+        "-1,-1,-1",
+        "0,0\n",
+        "1,1\n1,1",
+    ];
+
+    #[test]
+    fn test_malformed_inputs() {
+        let mut panicked = vec![];
+        for day in registry::days() {
+            let (year, day) = day.year_day();
+            for input in MALFORMED {
+                if std::panic::catch_unwind(|| fuzz_day(year, day, input.as_bytes())).is_err() {
+                    panicked.push(format!("{} day {:02} on {:?}", year, day, input));
+                }
+            }
+        }
+        assert!(panicked.is_empty(), "panicked: {:#?}", panicked);
+    }
+}
@@ -0,0 +1,86 @@
+use crate::aoclib::runner::AocDay;
+use std::ops::RangeInclusive;
+
+/// A small seeded pseudo random number generator (SplitMix64). Generated inputs only depend on
+/// the seed, so a stress test or benchmark input can be reproduced on any machine.
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `range`, which must not be empty.
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (low, high) = range.into_inner();
+        match (high - low).checked_add(1) {
+            Some(span) => low + ((self.next_u64() as u128 * span as u128) >> 64) as u64,
+            None => self.next_u64(),
+        }
+    }
+
+    /// `true` with the given probability.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        let unit = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        unit < probability
+    }
+
+    /// One of `items`, which must not be empty.
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..=items.len() as u64 - 1) as usize]
+    }
+}
+
+/// Generate a structurally valid input for `day`. What `size` counts (lines, grid rows, points)
+/// depends on the day.
+pub fn generate(day: &dyn AocDay, size: usize, seed: u64) -> Result<String, String> {
+    let (year, day_num) = day.year_day();
+    day.generate(size, seed)
+        .ok_or_else(|| format!("{} day {:02} has no input generator", year, day_num))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoclib::generate::{Rng, generate};
+    use crate::registry;
+
+    #[test]
+    fn test_rng() {
+        let mut rng = Rng::new(7);
+        let first: Vec<u64> = (0..4).map(|_| rng.next_u64()).collect();
+        let mut rng = Rng::new(7);
+        assert_eq!(first, (0..4).map(|_| rng.next_u64()).collect::<Vec<_>>());
+
+        for _ in 0..1000 {
+            assert!((3..=5).contains(&rng.range(3..=5)));
+        }
+        assert_eq!(9, rng.range(9..=9));
+        rng.range(0..=u64::MAX);
+        assert!(!rng.chance(0.0));
+        assert!(rng.chance(1.0));
+    }
+
+    #[test]
+    fn test_generated_inputs_solve() {
+        for day in registry::days() {
+            let (year, day_num) = day.year_day();
+            let input = generate(day.as_ref(), 30, 1).unwrap();
+
+            assert_eq!(input, generate(day.as_ref(), 30, 1).unwrap());
+            assert_ne!(input, generate(day.as_ref(), 30, 2).unwrap());
+            if let Err(e) = day.solve(&input) {
+                panic!("{} day {:02}: {}\n{}", year, day_num, e, input);
+            }
+        }
+    }
+}
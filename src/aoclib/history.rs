@@ -0,0 +1,355 @@
+use crate::aoclib::report::{DayReport, PhaseReport};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::str::FromStr;
+use std::sync::OnceLock;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Where timings are recorded. It's kept out of `target/` so `cargo clean` doesn't lose it.
+pub const HISTORY_PATH: &str = ".aoc-history.tsv";
+
+/// The commit the running build was made from, with `+dirty` when the tree has local changes,
+/// or `unknown` outside a git checkout.
+pub fn current_commit() -> &'static str {
+    static COMMIT: OnceLock<String> = OnceLock::new();
+    COMMIT.get_or_init(|| {
+        let git = |args: &[&str]| {
+            Command::new("git")
+                .args(args)
+                .output()
+                .ok()
+                .filter(|output| output.status.success())
+                .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        };
+        match git(&["rev-parse", "--short", "HEAD"]) {
+            Some(commit) if git(&["status", "--porcelain"]).is_some_and(|s| !s.is_empty()) => {
+                format!("{}+dirty", commit)
+            }
+            Some(commit) => commit,
+            None => "unknown".to_string(),
+        }
+    })
+}
+
+/// One timed phase of one run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    /// Seconds since the Unix epoch when the run was recorded.
+    pub timestamp: u64,
+    pub commit: String,
+    pub build: String,
+    pub year: u32,
+    pub day: u32,
+    /// `parse`, `part01` or `part02`.
+    pub phase: String,
+    /// Fingerprint of the input, as timings on different inputs aren't comparable.
+    pub fingerprint: String,
+    pub duration: Duration,
+}
+
+impl Entry {
+    /// The entries for every phase of a freshly computed report that produced an answer.
+    pub fn from_report(report: &DayReport, commit: &str, build: &str) -> Vec<Entry> {
+        let Some(fingerprint) = report.fingerprint.as_ref().filter(|_| !report.cached) else {
+            return vec![];
+        };
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        let phases: [(&str, Option<&PhaseReport>); 3] = [
+            ("parse", Some(&report.parse)),
+            ("part01", report.part01.as_ref()),
+            ("part02", report.part02.as_ref()),
+        ];
+        phases
+            .into_iter()
+            .filter_map(|(phase, report)| Some((phase, report.filter(|r| r.is_ok())?)))
+            .map(|(phase, phase_report)| Entry {
+                timestamp,
+                commit: commit.to_string(),
+                build: build.to_string(),
+                year: report.year,
+                day: report.day,
+                phase: phase.to_string(),
+                fingerprint: fingerprint.clone(),
+                duration: phase_report.duration,
+            })
+            .collect()
+    }
+}
+
+impl FromStr for Entry {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Invalid history line: '{}'", s);
+        let fields: Vec<&str> = s.split('\t').collect();
+        let [
+            timestamp,
+            commit,
+            build,
+            year,
+            day,
+            phase,
+            fingerprint,
+            nanos,
+        ] = fields[..]
+        else {
+            return Err(invalid());
+        };
+        Ok(Entry {
+            timestamp: timestamp.parse().map_err(|_| invalid())?,
+            commit: commit.to_string(),
+            build: build.to_string(),
+            year: year.parse().map_err(|_| invalid())?,
+            day: day.parse().map_err(|_| invalid())?,
+            phase: phase.to_string(),
+            fingerprint: fingerprint.to_string(),
+            duration: Duration::from_nanos(nanos.parse().map_err(|_| invalid())?),
+        })
+    }
+}
+
+impl fmt::Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            self.timestamp,
+            self.commit,
+            self.build,
+            self.year,
+            self.day,
+            self.phase,
+            self.fingerprint,
+            self.duration.as_nanos()
+        )
+    }
+}
+
+/// Timings of past runs, one entry per line in the order they were recorded.
+pub struct History {
+    path: PathBuf,
+    pub entries: Vec<Entry>,
+}
+
+impl History {
+    /// Load the history, treating a missing file as empty and skipping unreadable lines.
+    pub fn load(path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref().to_path_buf();
+        let entries = std::fs::read_to_string(&path)
+            .map(|contents| contents.lines().filter_map(|l| l.parse().ok()).collect())
+            .unwrap_or_default();
+        History { path, entries }
+    }
+
+    /// Append entries to the history file.
+    pub fn append(&mut self, entries: Vec<Entry>) -> Result<(), String> {
+        use std::io::Write;
+
+        if entries.is_empty() {
+            return Ok(());
+        }
+        let write_error =
+            |e: std::io::Error| format!("Failed to write {}: {}", self.path.display(), e);
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(write_error)?;
+        for entry in &entries {
+            writeln!(file, "{}", entry).map_err(write_error)?;
+        }
+        self.entries.extend(entries);
+        Ok(())
+    }
+
+    /// The trend of every recorded phase, ordered by year, day and phase. The latest timing of
+    /// each is compared with the median of up to `window` earlier timings on the same input.
+    pub fn trends(&self, window: usize) -> Vec<Trend> {
+        let mut phases: BTreeMap<(u32, u32, &str), Vec<&Entry>> = BTreeMap::new();
+        for entry in &self.entries {
+            phases
+                .entry((entry.year, entry.day, entry.phase.as_str()))
+                .or_default()
+                .push(entry);
+        }
+
+        phases
+            .into_iter()
+            .filter_map(|((year, day, phase), entries)| {
+                let (latest, earlier) = entries.split_last()?;
+                let mut recent: Vec<Duration> = earlier
+                    .iter()
+                    .filter(|entry| entry.fingerprint == latest.fingerprint)
+                    .map(|entry| entry.duration)
+                    .collect();
+                recent.drain(..recent.len().saturating_sub(window));
+                Some(Trend {
+                    year,
+                    day,
+                    phase: phase.to_string(),
+                    commit: latest.commit.clone(),
+                    latest: latest.duration,
+                    recent,
+                })
+            })
+            .collect()
+    }
+}
+
+/// The latest timing of a phase against its recent history.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trend {
+    pub year: u32,
+    pub day: u32,
+    pub phase: String,
+    /// Commit of the latest run.
+    pub commit: String,
+    pub latest: Duration,
+    /// Earlier timings on the same input, oldest first.
+    pub recent: Vec<Duration>,
+}
+
+impl Trend {
+    pub fn median(&self) -> Option<Duration> {
+        let mut sorted = self.recent.clone();
+        sorted.sort();
+        let middle = sorted.len() / 2;
+        match sorted.len() {
+            0 => None,
+            n if n % 2 == 0 => Some((sorted[middle - 1] + sorted[middle]) / 2),
+            _ => Some(sorted[middle]),
+        }
+    }
+
+    /// How much slower the latest timing is than the recent median, as a fraction.
+    pub fn change(&self) -> Option<f64> {
+        let median = self.median().filter(|median| !median.is_zero())?;
+        Some(self.latest.as_secs_f64() / median.as_secs_f64() - 1.0)
+    }
+
+    /// Whether the latest timing is more than `threshold` (a fraction) slower than the median.
+    pub fn regressed(&self, threshold: f64) -> bool {
+        self.change().is_some_and(|change| change > threshold)
+    }
+}
+
+impl fmt::Display for Trend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} day {:02} {:<6} {:>12} at {}",
+            self.year,
+            self.day,
+            self.phase,
+            format!("{:.2?}", self.latest),
+            self.commit
+        )?;
+        match (self.median(), self.change()) {
+            (Some(median), Some(change)) => write!(
+                f,
+                ", median {:.2?} of {} ({:+.1}%)",
+                median,
+                self.recent.len(),
+                change * 100.0
+            ),
+            _ => write!(f, ", no earlier runs on this input"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoclib::history::{Entry, History};
+    use crate::aoclib::report::{DayReport, Outcome, PhaseReport};
+    use std::time::Duration;
+
+    fn entry(phase: &str, fingerprint: &str, millis: u64) -> Entry {
+        Entry {
+            timestamp: 1,
+            commit: "abc1234".to_string(),
+            build: "0011223344556677".to_string(),
+            year: 2025,
+            day: 1,
+            phase: phase.to_string(),
+            fingerprint: fingerprint.to_string(),
+            duration: Duration::from_millis(millis),
+        }
+    }
+
+    fn history(entries: Vec<Entry>) -> History {
+        History {
+            path: "unused".into(),
+            entries,
+        }
+    }
+
+    #[test]
+    fn test_entry_round_trip() {
+        let entry = entry("part01", "f00d", 12);
+        assert_eq!(entry, entry.to_string().parse::<Entry>().unwrap());
+        assert!("1\tabc".parse::<Entry>().is_err());
+    }
+
+    #[test]
+    fn test_from_report() {
+        let phase = |outcome| PhaseReport {
+            outcome,
+            duration: Duration::from_millis(3),
+            alloc: None,
+        };
+        let mut report = DayReport::new(
+            2025,
+            4,
+            phase(Outcome::Answer(String::new())),
+            Some(phase(Outcome::Answer("1".to_string()))),
+            Some(phase(Outcome::Failed("no".to_string()))),
+        )
+        .with_fingerprint("f00d".to_string());
+
+        let entries = Entry::from_report(&report, "abc1234", "build");
+        let phases: Vec<&str> = entries.iter().map(|e| e.phase.as_str()).collect();
+        assert_eq!(vec!["parse", "part01"], phases);
+        assert_eq!("f00d", entries[0].fingerprint);
+
+        report.cached = true;
+        assert!(Entry::from_report(&report, "abc1234", "build").is_empty());
+    }
+
+    #[test]
+    fn test_trends() {
+        let history = history(vec![
+            entry("part01", "f00d", 100),
+            entry("part01", "f00d", 10),
+            entry("parse", "f00d", 5),
+            entry("part01", "beef", 1),
+            entry("part01", "f00d", 12),
+            entry("part01", "f00d", 11),
+            entry("part01", "f00d", 14),
+        ]);
+
+        let trends = history.trends(3);
+        assert_eq!(2, trends.len());
+        let (parse, part01) = (&trends[0], &trends[1]);
+
+        assert_eq!("parse", parse.phase);
+        assert_eq!(None, parse.median());
+        assert!(!parse.regressed(0.0));
+
+        // Only the last 3 earlier runs on the same input count.
+        assert_eq!(
+            vec![10, 12, 11],
+            part01
+                .recent
+                .iter()
+                .map(|d| d.as_millis())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(Some(Duration::from_millis(11)), part01.median());
+        assert!(part01.regressed(0.2));
+        assert!(!part01.regressed(0.3));
+    }
+}
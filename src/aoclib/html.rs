@@ -0,0 +1,244 @@
+use crate::aoclib::budget;
+use crate::aoclib::report::{DayReport, Outcome, PhaseReport};
+use crate::aoclib::submit::Verification;
+use std::fmt::Write;
+use std::path::{Component, Path, PathBuf};
+use std::time::Duration;
+
+/// Where Criterion writes its reports, see `benches/aoc_benchmarks.rs`.
+pub const CRITERION_DIR: &str = "target/criterion";
+
+/// A day's results and how each part's answer compares with the accepted one.
+pub struct HtmlRow {
+    pub report: DayReport,
+    pub parts: [Verification; 2],
+    /// Link to the day's Criterion report, relative to the HTML page.
+    pub criterion: Option<String>,
+}
+
+/// The Criterion report of a day's benchmark group, if the benches have been run.
+pub fn criterion_report(year: u32, day: u32) -> Option<PathBuf> {
+    let path = Path::new(CRITERION_DIR)
+        .join(format!("{} Day {:02}", year, day))
+        .join("report")
+        .join("index.html");
+    path.exists().then_some(path)
+}
+
+/// `to` relative to the directory `from`, when both exist.
+pub fn relative_link(from: &Path, to: &Path) -> Option<String> {
+    let from = from.canonicalize().ok()?;
+    let to = to.canonicalize().ok()?;
+    let common = from
+        .components()
+        .zip(to.components())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let ups = from.components().skip(common).map(|_| "..".to_string());
+    let downs = to
+        .components()
+        .skip(common)
+        .map(|component| match component {
+            Component::Normal(name) => name.to_string_lossy().replace(' ', "%20"),
+            _ => String::new(),
+        });
+    Some(ups.chain(downs).collect::<Vec<_>>().join("/"))
+}
+
+/// Write the page to `index.html` in `dir`, creating the directory.
+pub fn write_report(dir: &Path, rows: &[HtmlRow], commit: &str) -> Result<PathBuf, String> {
+    std::fs::create_dir_all(dir)
+        .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    let path = dir.join("index.html");
+    std::fs::write(&path, render(rows, commit))
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+    Ok(path)
+}
+
+const STYLE: &str = "
+body { font-family: sans-serif; margin: 2em; color: #222; }
+table { border-collapse: collapse; }
+th, td { padding: 0.3em 0.8em; border-bottom: 1px solid #ddd; text-align: left; }
+td.time { text-align: right; font-variant-numeric: tabular-nums; }
+.verified { color: #1a7f37; } .wrong, .failed { color: #cf222e; }
+.unverified { color: #9a6700; } .placeholder, .unsolved { color: #6e7781; }
+.chart div.row { display: flex; align-items: center; margin: 0.2em 0; }
+.chart span.label { width: 6em; }
+.chart div.bar { display: flex; height: 1em; }
+.parse { background: #8c959f; } .part01 { background: #0969da; } .part02 { background: #bf8700; }
+.legend span { display: inline-block; width: 1em; height: 1em; margin: 0 0.3em 0 1em; }
+";
+
+/// Render a self-contained page with a table of the days and a chart of their cost.
+pub fn render(rows: &[HtmlRow], commit: &str) -> String {
+    let mut html = String::new();
+    let _ = write!(
+        html,
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>Advent of Code results</title>\n<style>{}</style>\n</head>\n<body>\n\
+         <h1>Advent of Code results</h1>\n<p>Commit {}</p>\n",
+        STYLE,
+        escape(commit)
+    );
+
+    html += "<table>\n<tr><th>Day</th><th>Part 01</th><th>Part 02</th><th>Parse</th>\
+             <th>Part 01</th><th>Part 02</th><th>Total</th><th>Benchmarks</th></tr>\n";
+    for row in rows {
+        let report = &row.report;
+        let _ = write!(html, "<tr><td>{} day {:02}</td>", report.year, report.day);
+        for (phase, verification) in [&report.part01, &report.part02].iter().zip(row.parts) {
+            let _ = write!(
+                html,
+                "<td class=\"{}\" title=\"{}\">{}</td>",
+                verification,
+                verification,
+                escape(&outcome(phase.as_ref()))
+            );
+        }
+        for phase in [
+            Some(&report.parse),
+            report.part01.as_ref(),
+            report.part02.as_ref(),
+        ] {
+            match phase {
+                Some(phase) if !report.cached => {
+                    let _ = write!(html, "<td class=\"time\">{:.2?}</td>", phase.duration);
+                }
+                _ => html += "<td></td>",
+            }
+        }
+        let _ = write!(html, "<td class=\"time\">{:.2?}</td>", budget::cost(report));
+        match &row.criterion {
+            Some(link) => {
+                let _ = write!(html, "<td><a href=\"{}\">Criterion</a></td>", escape(link));
+            }
+            None => html += "<td></td>",
+        }
+        html += "</tr>\n";
+    }
+    html += "</table>\n";
+
+    html += "<h2>Cost</h2>\n<p class=\"legend\"><span class=\"parse\"></span>Parse\
+             <span class=\"part01\"></span>Part 01<span class=\"part02\"></span>Part 02</p>\n\
+             <div class=\"chart\">\n";
+    let longest = rows
+        .iter()
+        .map(|row| budget::cost(&row.report))
+        .max()
+        .filter(|longest| !longest.is_zero())
+        .unwrap_or(Duration::from_nanos(1));
+    for row in rows {
+        let report = &row.report;
+        let _ = write!(
+            html,
+            "<div class=\"row\"><span class=\"label\">Day {:02}</span><div class=\"bar\">",
+            report.day
+        );
+        let phases = [
+            ("parse", Some(&report.parse)),
+            ("part01", report.part01.as_ref()),
+            ("part02", report.part02.as_ref()),
+        ];
+        for (class, phase) in phases {
+            let Some(phase) = phase else { continue };
+            let width = phase.duration.as_secs_f64() / longest.as_secs_f64() * 40.0;
+            let _ = write!(
+                html,
+                "<div class=\"{}\" style=\"width: {:.3}em\" title=\"{} {:.2?}\"></div>",
+                class, width, class, phase.duration
+            );
+        }
+        let _ = writeln!(html, "</div>&nbsp;{:.2?}</div>", budget::cost(report));
+    }
+    html += "</div>\n</body>\n</html>\n";
+    html
+}
+
+/// The answer of a phase, or what went wrong.
+fn outcome(phase: Option<&PhaseReport>) -> String {
+    match phase.map(|phase| &phase.outcome) {
+        Some(Outcome::Answer(answer)) => answer.clone(),
+        Some(Outcome::Failed(error)) => format!("FAILED: {}", error),
+        Some(Outcome::Panicked(panic)) => format!("FAILED: {}", panic),
+        Some(Outcome::TimedOut(limit)) => format!("TIMED OUT after {:?}", limit),
+        None => "not run".to_string(),
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoclib::html::{HtmlRow, relative_link, render};
+    use crate::aoclib::report::{DayReport, Outcome, PhaseReport};
+    use crate::aoclib::submit::Verification;
+    use std::path::Path;
+    use std::time::Duration;
+
+    fn phase(outcome: Outcome, millis: u64) -> PhaseReport {
+        PhaseReport {
+            outcome,
+            duration: Duration::from_millis(millis),
+            alloc: None,
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let rows = [
+            HtmlRow {
+                report: DayReport::new(
+                    2025,
+                    1,
+                    phase(Outcome::Answer(String::new()), 1),
+                    Some(phase(Outcome::Answer("42".to_string()), 2)),
+                    Some(phase(Outcome::Failed("x < y".to_string()), 3)),
+                ),
+                parts: [Verification::Verified, Verification::Failed],
+                criterion: Some(
+                    "../target/criterion/2025%20Day%2001/report/index.html".to_string(),
+                ),
+            },
+            HtmlRow {
+                report: DayReport::parse_failed(
+                    2025,
+                    2,
+                    phase(Outcome::Failed("no input".to_string()), 0),
+                ),
+                parts: [Verification::Failed, Verification::Unsolved],
+                criterion: None,
+            },
+        ];
+
+        let html = render(&rows, "abc1234");
+        assert!(html.contains("<td class=\"verified\" title=\"verified\">42</td>"));
+        assert!(html.contains("FAILED: x &lt; y"));
+        assert!(html.contains("<td class=\"unsolved\" title=\"unsolved\">not run</td>"));
+        assert!(html.contains("<td class=\"time\">6.00ms</td>"));
+        assert!(html.contains("href=\"../target/criterion/2025%20Day%2001/report/index.html\""));
+        // The most expensive day's bar is full width.
+        assert!(html.contains("style=\"width: 20.000em\" title=\"part02 3.00ms\""));
+        assert!(!html.contains("<script"));
+    }
+
+    #[test]
+    fn test_relative_link() {
+        let root = std::env::temp_dir().join(format!("aoc-html-{}", std::process::id()));
+        let out = root.join("out");
+        let report = root.join("target").join("criterion").join("2025 Day 01");
+        std::fs::create_dir_all(&out).unwrap();
+        std::fs::create_dir_all(&report).unwrap();
+
+        assert_eq!(
+            Some("../target/criterion/2025%20Day%2001".to_string()),
+            relative_link(&out, &report)
+        );
+        assert_eq!(None, relative_link(&out, Path::new("/does/not/exist")));
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
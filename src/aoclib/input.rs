@@ -0,0 +1,143 @@
+use crate::aoclib::crypto::{self, InputKey};
+use std::path::Path;
+
+/// Normalise raw puzzle input before it reaches a day's parser.
+///
+/// Strips a leading UTF-8 byte order mark, converts `\r\n` and lone `\r` line endings to `\n`
+/// and, when `trim_trailing_blank` is set, drops any blank lines at the end of the input so
+/// that the last line is real puzzle data. Whitespace inside lines is left untouched since some
+/// days (e.g. Day06) depend on column positions.
+pub fn normalize(input: &str, trim_trailing_blank: bool) -> String {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let mut normalized = input.replace("\r\n", "\n").replace('\r', "\n");
+
+    if trim_trailing_blank {
+        let had_newline = normalized.ends_with('\n');
+        let trimmed_len = normalized.trim_end_matches('\n').len();
+        normalized.truncate(trimmed_len);
+        if had_newline && !normalized.is_empty() {
+            normalized.push('\n');
+        }
+    }
+
+    normalized
+}
+
+/// Load a puzzle input from `path`, falling back to its encrypted copy `<path>.enc`, which is
+/// decrypted with the key from the `AOC_INPUT_KEY` environment variable.
+///
+/// Returns `Ok(None)` when the input is missing, including when only the encrypted copy exists
+/// and no key is set.
+pub fn load(path: &str) -> Result<Option<String>, String> {
+    load_with_key(path, InputKey::from_env()?.as_ref())
+}
+
+pub fn load_with_key(path: &str, key: Option<&InputKey>) -> Result<Option<String>, String> {
+    if Path::new(path).exists() {
+        return std::fs::read_to_string(path)
+            .map(Some)
+            .map_err(|e| format!("Failed to read input file {}: {}", path, e));
+    }
+
+    let encrypted_path = crypto::encrypted_path(path);
+    let Some(key) = key.filter(|_| Path::new(&encrypted_path).exists()) else {
+        return Ok(None);
+    };
+    let encrypted = std::fs::read(&encrypted_path)
+        .map_err(|e| format!("Failed to read input file {}: {}", encrypted_path, e))?;
+    let decrypted = key
+        .decrypt(&encrypted)
+        .map_err(|e| format!("{}: {}", encrypted_path, e))?;
+    String::from_utf8(decrypted)
+        .map(Some)
+        .map_err(|e| format!("{}: {}", encrypted_path, e))
+}
+
+/// Whether [`load`] would find an input at `path`, without decrypting it. An invalid key counts
+/// as set, so that [`load`] reports it.
+pub fn exists(path: &str) -> bool {
+    Path::new(path).exists()
+        || (Path::new(&crypto::encrypted_path(path)).exists()
+            && !matches!(InputKey::from_env(), Ok(None)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoc2025;
+    use crate::aoclib::crypto::{self, InputKey};
+    use crate::aoclib::input::{load_with_key, normalize};
+
+    #[test]
+    fn test_normalize_line_endings() {
+        assert_eq!("a\nb\nc\n", normalize("a\r\nb\rc\r\n", false));
+        assert_eq!("a\nb", normalize("a\nb", false));
+    }
+
+    #[test]
+    fn test_normalize_bom() {
+        assert_eq!("L68\n", normalize("\u{feff}L68\r\n", false));
+        assert_eq!("x\u{feff}", normalize("x\u{feff}", false));
+    }
+
+    #[test]
+    fn test_normalize_trailing_blank_lines() {
+        assert_eq!("a\nb\n", normalize("a\nb\n\n\n", true));
+        assert_eq!("a\nb\n\n\n", normalize("a\nb\n\n\n", false));
+        assert_eq!("a\n\nb\n", normalize("a\r\n\r\nb\r\n\r\n", true));
+        assert_eq!("", normalize("\n\n", true));
+    }
+
+    #[test]
+    fn test_samples_lf_and_crlf() {
+        for day in aoc2025::days() {
+            let path = day.test_input_path();
+            let lf = std::fs::read_to_string(&path)
+                .unwrap_or_else(|e| panic!("Failed to read input file {}: {}", path, e));
+            let lf = lf.replace("\r\n", "\n");
+            let crlf = lf.replace('\n', "\r\n");
+            let bom_crlf = format!("\u{feff}{}\r\n", crlf);
+
+            let expected = day.solve(&normalize(&lf, true));
+            assert!(
+                expected.is_ok(),
+                "{} failed on LF sample: {:?}",
+                path,
+                expected
+            );
+            assert_eq!(
+                expected,
+                day.solve(&normalize(&crlf, true)),
+                "{} CRLF",
+                path
+            );
+            assert_eq!(
+                expected,
+                day.solve(&normalize(&bom_crlf, true)),
+                "{} BOM+CRLF",
+                path
+            );
+        }
+    }
+
+    #[test]
+    fn test_load_encrypted_fallback() {
+        let dir = std::env::temp_dir().join(format!("aoc-input-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("day01.input").display().to_string();
+        let key = InputKey::generate();
+
+        assert_eq!(Ok(None), load_with_key(&path, Some(&key)));
+
+        std::fs::write(crypto::encrypted_path(&path), key.encrypt(b"R48\n")).unwrap();
+        assert_eq!(
+            Ok(Some("R48\n".to_string())),
+            load_with_key(&path, Some(&key))
+        );
+        assert_eq!(Ok(None), load_with_key(&path, None));
+        assert!(load_with_key(&path, Some(&InputKey::generate())).is_err());
+
+        std::fs::write(&path, "L68\n").unwrap();
+        assert_eq!(Ok(Some("L68\n".to_string())), load_with_key(&path, None));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
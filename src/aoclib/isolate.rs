@@ -0,0 +1,109 @@
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+/// The message and source location of a panic caught by [`catch`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PanicReport {
+    pub message: String,
+    pub location: Option<String>,
+}
+
+impl fmt::Display for PanicReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "panicked at {}: {}", location, self.message),
+            None => write!(f, "panicked: {}", self.message),
+        }
+    }
+}
+
+thread_local! {
+    static CATCH_DEPTH: Cell<usize> = const { Cell::new(0) };
+    static LAST_PANIC: RefCell<Option<PanicReport>> = const { RefCell::new(None) };
+}
+
+static INSTALL_HOOK: Once = Once::new();
+
+/// Install a panic hook that records panics raised inside [`catch`] instead of printing them.
+/// Panics anywhere else are forwarded to the previously installed hook.
+fn install_hook() {
+    INSTALL_HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCH_DEPTH.with(|depth| depth.get()) == 0 {
+                previous(info);
+                return;
+            }
+            let report = PanicReport {
+                message: payload_message(info.payload()),
+                location: info.location().map(|l| l.to_string()),
+            };
+            LAST_PANIC.with(|last| *last.borrow_mut() = Some(report));
+        }));
+    });
+}
+
+fn payload_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "Box<dyn Any>".to_string()
+    }
+}
+
+/// Run `f`, converting a panic into a [`PanicReport`] rather than unwinding into the caller.
+///
+/// Panics raised on other threads (e.g. inside a rayon pool) are still reported by their
+/// message, but without a location.
+pub fn catch<T>(f: impl FnOnce() -> T) -> Result<T, PanicReport> {
+    install_hook();
+    CATCH_DEPTH.with(|depth| depth.set(depth.get() + 1));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCH_DEPTH.with(|depth| depth.set(depth.get() - 1));
+
+    result.map_err(|payload| {
+        LAST_PANIC
+            .with(|last| last.borrow_mut().take())
+            .unwrap_or_else(|| PanicReport {
+                message: payload_message(payload.as_ref()),
+                location: None,
+            })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoclib::isolate::catch;
+
+    #[test]
+    fn test_catch_ok() {
+        assert_eq!(Ok(42), catch(|| 42));
+    }
+
+    #[test]
+    fn test_catch_panic() {
+        let report = catch(|| {
+            let empty: Vec<u32> = vec![];
+            empty.iter().max().copied().unwrap()
+        })
+        .unwrap_err();
+
+        assert!(report.message.contains("None"), "{}", report.message);
+        assert!(
+            report
+                .location
+                .unwrap()
+                .starts_with("src/aoclib/isolate.rs:")
+        );
+    }
+
+    #[test]
+    fn test_catch_formatted_panic() {
+        let report = catch(|| panic!("day {} exploded", 7)).unwrap_err();
+        assert_eq!("day 7 exploded", report.message);
+    }
+}
@@ -0,0 +1,279 @@
+use crate::aoclib::crosscheck;
+use crate::aoclib::report::Outcome;
+use crate::aoclib::runner::{AocDay, Params, RunOptions};
+use std::str::FromStr;
+
+/// The problem an input must keep showing while it is minimized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Predicate {
+    /// The variants of a solved part give different answers.
+    Disagree,
+    /// Parsing or a part panics.
+    Panic,
+    /// The input is rejected by the parser.
+    ParseError,
+}
+
+impl FromStr for Predicate {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "disagree" => Ok(Predicate::Disagree),
+            "panic" => Ok(Predicate::Panic),
+            "parse-error" => Ok(Predicate::ParseError),
+            _ => Err(format!(
+                "Unknown predicate '{}', expected disagree, panic or parse-error",
+                s
+            )),
+        }
+    }
+}
+
+impl Predicate {
+    /// Whether running `day` on `input` shows the problem.
+    pub fn holds(&self, day: &dyn AocDay, input: &str, options: &RunOptions) -> bool {
+        match self {
+            Predicate::Disagree => day
+                .run_variants(input, &Params::new(), options)
+                .is_ok_and(|runs| crosscheck::disagrees(day, &runs)),
+            Predicate::Panic => {
+                let report = day.run_input(input, &Params::new(), options);
+                [
+                    Some(&report.parse),
+                    report.part01.as_ref(),
+                    report.part02.as_ref(),
+                ]
+                .into_iter()
+                .flatten()
+                .any(|phase| matches!(phase.outcome, Outcome::Panicked(_)))
+            }
+            Predicate::ParseError => day.run_variants(input, &Params::new(), options).is_err(),
+        }
+    }
+}
+
+/// Shrink `input` while `holds` stays true: drop runs of lines, then comma separated items
+/// within each line, then shrink each number, until none of these makes progress.
+pub fn minimize(input: &str, mut holds: impl FnMut(&str) -> bool) -> Result<String, String> {
+    if !holds(input) {
+        return Err("The predicate does not hold on the input".to_string());
+    }
+
+    let mut current = input.to_string();
+    loop {
+        let before = current.clone();
+        current = reduce_items(&current, "\n", &mut holds);
+        let line_count = current.split('\n').count();
+        for line in 0..line_count {
+            current = reduce_line_items(&current, line, &mut holds);
+        }
+        current = shrink_numbers(&current, &mut holds);
+        if current == before {
+            return Ok(current);
+        }
+    }
+}
+
+/// Drop chunks of `separator` separated items, halving the chunk size down to single items.
+fn reduce_items(text: &str, separator: &str, holds: &mut impl FnMut(&str) -> bool) -> String {
+    let mut items: Vec<&str> = text.split(separator).collect();
+    let mut chunk = items.len() / 2;
+    while chunk > 0 {
+        let mut start = 0;
+        while start < items.len() {
+            let end = (start + chunk).min(items.len());
+            let candidate = [&items[..start], &items[end..]].concat();
+            if holds(&candidate.join(separator)) {
+                items = candidate;
+            } else {
+                start = end;
+            }
+        }
+        chunk /= 2;
+    }
+    items.join(separator)
+}
+
+/// Drop comma separated items from line `index` of `text`.
+fn reduce_line_items(text: &str, index: usize, holds: &mut impl FnMut(&str) -> bool) -> String {
+    let lines: Vec<&str> = text.split('\n').collect();
+    if !lines[index].contains(',') {
+        return text.to_string();
+    }
+    let with_line = |line: &str| {
+        let mut lines = lines.clone();
+        lines[index] = line;
+        lines.join("\n")
+    };
+    let line = reduce_items(lines[index], ",", &mut |line| holds(&with_line(line)));
+    with_line(&line)
+}
+
+/// Replace each number with a smaller one (0, 1, half, a digit fewer or one less) for as long
+/// as one of them keeps the predicate. The end of an `a-b` range moves along with its start, as
+/// shrinking the start alone would widen the range, and with it the work of a naive solution.
+fn shrink_numbers(text: &str, holds: &mut impl FnMut(&str) -> bool) -> String {
+    let mut text = text.to_string();
+    let mut position = 0;
+    while let Some(offset) = text[position..].find(|c: char| c.is_ascii_digit()) {
+        let start = position + offset;
+        while let (Some(value), end) = number_at(&text, start) {
+            let range = text[end..]
+                .strip_prefix('-')
+                .filter(|rest| rest.starts_with(|c: char| c.is_ascii_digit()))
+                .map(|_| number_at(&text, end + 1))
+                .and_then(|(upper, upper_end)| Some((upper?.checked_sub(value)?, upper_end)));
+            let replace = |smaller: u128| match range {
+                Some((width, upper_end)) => format!(
+                    "{}{}-{}{}",
+                    &text[..start],
+                    smaller,
+                    smaller + width,
+                    &text[upper_end..]
+                ),
+                None => format!("{}{}{}", &text[..start], smaller, &text[end..]),
+            };
+            let smaller = [0, 1, value / 2, value / 10, value.saturating_sub(1)]
+                .into_iter()
+                .filter(|&candidate| candidate < value)
+                .map(replace)
+                .find(|candidate| holds(candidate));
+            let Some(smaller) = smaller else {
+                break;
+            };
+            text = smaller;
+        }
+        position = number_at(&text, start).1;
+    }
+    text
+}
+
+/// The number starting at byte `start` of `text`, if it fits in a `u128`, and where it ends.
+fn number_at(text: &str, start: usize) -> (Option<u128>, usize) {
+    let end = text[start..]
+        .find(|c: char| !c.is_ascii_digit())
+        .map_or(text.len(), |length| start + length);
+    (text[start..end].parse().ok(), end)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoclib::minimize::{Predicate, minimize};
+    use crate::aoclib::runner::{RunOptions, Runner, Variant};
+    use crate::registry;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_minimize_lines_and_numbers() {
+        let input = "3\n17\n250\n4\n99\n8";
+        let minimal = minimize(input, |text| {
+            text.lines()
+                .filter_map(|line| line.parse::<u32>().ok())
+                .any(|n| n > 42)
+        });
+
+        assert_eq!(Ok("43".to_string()), minimal);
+        assert!(minimize(input, |_| false).is_err());
+    }
+
+    #[test]
+    fn test_minimize_comma_items() {
+        let minimal = minimize("1-5,20-30,7-9\n", |text| text.contains("20-"));
+        assert_eq!(Ok("20-0".to_string()), minimal);
+    }
+
+    #[test]
+    fn test_minimize_keeps_range_width() {
+        let mut widest = 0;
+        let minimal = minimize("10-20\n", |text| {
+            let Some((start, end)) = text.trim().split_once('-') else {
+                return false;
+            };
+            let (Ok(start), Ok(end)) = (start.parse::<u32>(), end.parse::<u32>()) else {
+                return false;
+            };
+            widest = widest.max(end.saturating_sub(start));
+            (start..=end).contains(&15)
+        });
+
+        assert_eq!(Ok("5-15".to_string()), minimal);
+        assert_eq!(10, widest);
+    }
+
+    /// Sums the numbers of its input, except that the `buggy` variant of part 1 drops numbers
+    /// above 1000 and part 2 panics on a 7.
+    #[derive(Debug, Default)]
+    struct Numbers(Vec<u64>);
+
+    impl FromStr for Numbers {
+        type Err = String;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            s.lines()
+                .map(|line| {
+                    line.parse()
+                        .map_err(|_| format!("Invalid number: {}", line))
+                })
+                .collect::<Result<_, _>>()
+                .map(Numbers)
+        }
+    }
+
+    impl Runner for Numbers {
+        type Output = u64;
+
+        fn name(&self) -> (u32, u32) {
+            (2025, 99)
+        }
+
+        fn part01(&self) -> Self::Output {
+            self.0.iter().sum()
+        }
+
+        fn part02(&self) -> Self::Output {
+            assert!(!self.0.contains(&7), "found a 7");
+            0
+        }
+
+        fn variants(&self, part: u32) -> Vec<Variant<Self>> {
+            match part {
+                1 => vec![
+                    ("default", Self::part01),
+                    ("buggy", |day| day.0.iter().filter(|&&n| n <= 1000).sum()),
+                ],
+                _ => vec![("default", Self::part02)],
+            }
+        }
+    }
+
+    #[test]
+    fn test_predicates() {
+        let options = RunOptions::default();
+        let min = |predicate: Predicate, input: &str| {
+            minimize(input, |text| {
+                predicate.holds(&Numbers::default(), text, &options)
+            })
+        };
+
+        assert_eq!(
+            Ok("1001".to_string()),
+            min(Predicate::Disagree, "5\n12\n3000\n40")
+        );
+        assert_eq!(Ok("7".to_string()), min(Predicate::Panic, "5\n17\n7\n40"));
+        assert_eq!(
+            Ok("x0".to_string()),
+            min(Predicate::ParseError, "5\n17\nx9\n40")
+        );
+    }
+
+    #[test]
+    fn test_day_predicate() {
+        let day = registry::find(Some(2025), 1).unwrap();
+        let options = RunOptions::default();
+        let minimal = minimize("L68\nL30\nX48\nR5", |text| {
+            Predicate::ParseError.holds(day.as_ref(), text, &options)
+        });
+        assert_eq!(Ok("X0".to_string()), minimal);
+    }
+}
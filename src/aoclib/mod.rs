@@ -1 +1,28 @@
+pub mod alloc;
+pub mod budget;
+pub mod cache;
+pub mod calendar;
+pub mod callgrind;
+pub mod cancel;
+pub mod cli;
+pub mod client;
+pub mod crosscheck;
+pub mod crypto;
+pub mod examples;
+pub mod extract;
+pub mod fetch;
+pub mod fuzz;
+pub mod generate;
+pub mod history;
+pub mod html;
+pub mod input;
+pub mod isolate;
+pub mod minimize;
+pub mod readme;
+pub mod report;
 pub mod runner;
+pub mod scaling;
+pub mod submit;
+#[cfg(test)]
+pub(crate) mod test_server;
+pub mod watch;
@@ -0,0 +1,114 @@
+use crate::aoclib::runner::PartStatus;
+use std::time::Duration;
+
+pub const README_PATH: &str = "README.md";
+
+/// The generated section of the README sits between these lines.
+pub const START_MARKER: &str = "<!-- progress:start -->";
+pub const END_MARKER: &str = "<!-- progress:end -->";
+
+/// One registered day in the progress table.
+pub struct ProgressRow {
+    pub year: u32,
+    pub day: u32,
+    pub title: Option<&'static str>,
+    pub parts: [PartStatus; 2],
+    /// Total time of the latest recorded release run, if there is one.
+    pub time: Option<Duration>,
+}
+
+/// A markdown table of the days, with a star for every solved part.
+pub fn table(rows: &[ProgressRow]) -> String {
+    let stars = rows
+        .iter()
+        .flat_map(|row| row.parts)
+        .filter(|&status| status == PartStatus::Solved)
+        .count();
+    let mut table = format!(
+        "{} of {} stars.\n\n| Day | Puzzle | Part 1 | Part 2 | Time |\n|---|---|---|---|---:|\n",
+        stars,
+        rows.len() * 2
+    );
+    for row in rows {
+        let [part01, part02] = row.parts.map(|status| match status {
+            PartStatus::Solved => "⭐",
+            PartStatus::Placeholder => "placeholder",
+            PartStatus::Unsolved => "unsolved",
+        });
+        let title = row
+            .title
+            .map_or_else(|| format!("Day {}", row.day), str::to_string);
+        let time = row
+            .time
+            .map_or_else(|| "-".to_string(), |time| format!("{:.2?}", time));
+        table += &format!(
+            "| {} day {:02} | [{}](https://adventofcode.com/{}/day/{}) | {} | {} | {} |\n",
+            row.year, row.day, title, row.year, row.day, part01, part02, time
+        );
+    }
+    table
+}
+
+/// Replace what's between the markers in `readme` with `section`.
+pub fn replace_section(readme: &str, section: &str) -> Result<String, String> {
+    let missing = || {
+        format!(
+            "The README needs a '{}' line and a '{}' line around the progress table",
+            START_MARKER, END_MARKER
+        )
+    };
+    let start = readme.find(START_MARKER).ok_or_else(missing)? + START_MARKER.len();
+    let end = start + readme[start..].find(END_MARKER).ok_or_else(missing)?;
+    Ok(format!(
+        "{}\n{}{}",
+        &readme[..start],
+        section,
+        &readme[end..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoclib::readme::{ProgressRow, replace_section, table};
+    use crate::aoclib::runner::PartStatus;
+    use std::time::Duration;
+
+    #[test]
+    fn test_table() {
+        let rows = [
+            ProgressRow {
+                year: 2025,
+                day: 1,
+                title: Some("Secret Entrance"),
+                parts: [PartStatus::Solved, PartStatus::Solved],
+                time: Some(Duration::from_micros(1500)),
+            },
+            ProgressRow {
+                year: 2025,
+                day: 9,
+                title: None,
+                parts: [PartStatus::Solved, PartStatus::Placeholder],
+                time: None,
+            },
+        ];
+        assert_eq!(
+            "3 of 4 stars.\n\n\
+             | Day | Puzzle | Part 1 | Part 2 | Time |\n\
+             |---|---|---|---|---:|\n\
+             | 2025 day 01 | [Secret Entrance](https://adventofcode.com/2025/day/1) | ⭐ | ⭐ | 1.50ms |\n\
+             | 2025 day 09 | [Day 9](https://adventofcode.com/2025/day/9) | ⭐ | placeholder | - |\n",
+            table(&rows)
+        );
+    }
+
+    #[test]
+    fn test_replace_section() {
+        let readme = "# AoC\n<!-- progress:start -->\nold\n<!-- progress:end -->\nrest\n";
+        assert_eq!(
+            "# AoC\n<!-- progress:start -->\nnew\n<!-- progress:end -->\nrest\n",
+            replace_section(readme, "new\n").unwrap()
+        );
+        assert!(replace_section("# AoC\n", "new\n").is_err());
+        assert!(replace_section("<!-- progress:end --><!-- progress:start -->", "").is_err());
+    }
+}
@@ -0,0 +1,262 @@
+use crate::aoclib::alloc::AllocStats;
+use crate::aoclib::isolate::PanicReport;
+use std::fmt;
+use std::time::Duration;
+
+/// The result of a single phase (parse, part 01 or part 02) of a day.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    Answer(String),
+    Failed(String),
+    Panicked(PanicReport),
+    TimedOut(Duration),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhaseReport {
+    pub outcome: Outcome,
+    pub duration: Duration,
+    /// Heap usage of the phase, when built with the `count-allocations` feature.
+    pub alloc: Option<AllocStats>,
+}
+
+impl PhaseReport {
+    /// A phase that failed before doing any measurable work.
+    pub fn failed(error: String) -> Self {
+        PhaseReport {
+            outcome: Outcome::Failed(error),
+            duration: Duration::ZERO,
+            alloc: None,
+        }
+    }
+
+    pub fn is_ok(&self) -> bool {
+        matches!(self.outcome, Outcome::Answer(_))
+    }
+
+    pub fn answer(&self) -> Option<&str> {
+        match &self.outcome {
+            Outcome::Answer(answer) => Some(answer),
+            _ => None,
+        }
+    }
+
+    /// Write the duration and, when collected, the allocation statistics of the phase.
+    fn fmt_cost(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{:?}]", self.duration)?;
+        match &self.alloc {
+            Some(alloc) => write!(f, " ({})", alloc),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Structured results of running a day. Parts are `None` when they never ran because an
+/// earlier phase failed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayReport {
+    pub year: u32,
+    pub day: u32,
+    pub parse: PhaseReport,
+    pub part01: Option<PhaseReport>,
+    pub part02: Option<PhaseReport>,
+    /// Content hash of the input the day ran on, to trace results back to an input.
+    pub fingerprint: Option<String>,
+    /// Whether the answers were read from the answer cache instead of computed.
+    pub cached: bool,
+}
+
+impl DayReport {
+    /// A report for a freshly computed day, without a fingerprint.
+    pub fn new(
+        year: u32,
+        day: u32,
+        parse: PhaseReport,
+        part01: Option<PhaseReport>,
+        part02: Option<PhaseReport>,
+    ) -> Self {
+        DayReport {
+            year,
+            day,
+            parse,
+            part01,
+            part02,
+            fingerprint: None,
+            cached: false,
+        }
+    }
+
+    /// A report for a day whose input could not be read or parsed, so neither part ran.
+    pub fn parse_failed(year: u32, day: u32, parse: PhaseReport) -> Self {
+        DayReport::new(year, day, parse, None, None)
+    }
+
+    pub fn with_fingerprint(self, fingerprint: String) -> Self {
+        DayReport {
+            fingerprint: Some(fingerprint),
+            ..self
+        }
+    }
+
+    /// Whether every phase of the day ran and produced an answer.
+    pub fn is_ok(&self) -> bool {
+        self.parse.is_ok()
+            && self.part01.as_ref().is_some_and(PhaseReport::is_ok)
+            && self.part02.as_ref().is_some_and(PhaseReport::is_ok)
+    }
+}
+
+impl fmt::Display for PhaseReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.outcome {
+            Outcome::Answer(answer) => write!(f, "{} ", answer)?,
+            Outcome::Failed(error) => write!(f, "FAILED: {} ", error)?,
+            Outcome::Panicked(panic) => write!(f, "FAILED: {} ", panic)?,
+            Outcome::TimedOut(limit) => {
+                return write!(
+                    f,
+                    "TIMED OUT after {:?}, part still running in background",
+                    limit
+                );
+            }
+        }
+        self.fmt_cost(f)
+    }
+}
+
+impl fmt::Display for DayReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Day {:02} Results", self.day)?;
+        match (&self.fingerprint, self.cached) {
+            (Some(fingerprint), false) => write!(f, " (input {}):", fingerprint)?,
+            (Some(fingerprint), true) => write!(f, " (input {}, cached):", fingerprint)?,
+            (None, _) => write!(f, ":")?,
+        }
+        if self.cached {
+            for (label, part) in [("01", &self.part01), ("02", &self.part02)] {
+                if let Some(answer) = part.as_ref().and_then(PhaseReport::answer) {
+                    write!(f, "\n\tPart {}: {}", label, answer)?;
+                }
+            }
+            return Ok(());
+        }
+        match &self.parse.outcome {
+            Outcome::Answer(_) => {
+                write!(f, "\n\tParse: ")?;
+                self.parse.fmt_cost(f)?
+            }
+            _ => write!(f, "\n\tParse: {}", self.parse)?,
+        }
+        if let Some(part01) = &self.part01 {
+            write!(f, "\n\tPart 01: {}", part01)?;
+        }
+        if let Some(part02) = &self.part02 {
+            write!(f, "\n\tPart 02: {}", part02)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoclib::alloc::AllocStats;
+    use crate::aoclib::isolate::PanicReport;
+    use crate::aoclib::report::{DayReport, Outcome, PhaseReport};
+    use std::time::Duration;
+
+    fn phase(outcome: Outcome) -> PhaseReport {
+        PhaseReport {
+            outcome,
+            duration: Duration::from_millis(1),
+            alloc: None,
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        let report = DayReport {
+            year: 2025,
+            day: 7,
+            parse: phase(Outcome::Answer(String::new())),
+            part01: Some(phase(Outcome::Answer("21".to_string()))),
+            part02: Some(phase(Outcome::Panicked(PanicReport {
+                message: "attempt to subtract with overflow".to_string(),
+                location: Some("src/aoc2025/day07.rs:66:17".to_string()),
+            }))),
+            fingerprint: None,
+            cached: false,
+        };
+
+        assert!(!report.is_ok());
+        assert_eq!(
+            "Day 07 Results:\n\tParse: [1ms]\n\tPart 01: 21 [1ms]\n\tPart 02: FAILED: panicked at \
+             src/aoc2025/day07.rs:66:17: attempt to subtract with overflow [1ms]",
+            report.to_string()
+        );
+    }
+
+    #[test]
+    fn test_display_timed_out() {
+        let part = phase(Outcome::TimedOut(Duration::from_secs(5)));
+        assert_eq!(
+            "TIMED OUT after 5s, part still running in background",
+            part.to_string()
+        );
+    }
+
+    #[test]
+    fn test_display_parse_failure() {
+        let report = DayReport {
+            year: 2025,
+            day: 1,
+            parse: phase(Outcome::Failed("Invalid number in line: L".to_string())),
+            part01: None,
+            part02: None,
+            fingerprint: None,
+            cached: false,
+        };
+
+        assert_eq!(
+            "Day 01 Results:\n\tParse: FAILED: Invalid number in line: L [1ms]",
+            report.to_string()
+        );
+    }
+
+    #[test]
+    fn test_display_fingerprint() {
+        let report = DayReport::new(
+            2025,
+            1,
+            phase(Outcome::Answer(String::new())),
+            Some(phase(Outcome::Answer("3".to_string()))),
+            Some(phase(Outcome::Answer("6".to_string()))),
+        )
+        .with_fingerprint("0123456789abcdef".to_string());
+
+        assert_eq!(
+            "Day 01 Results (input 0123456789abcdef):\n\tParse: [1ms]\n\tPart 01: 3 [1ms]\n\t\
+             Part 02: 6 [1ms]",
+            report.to_string()
+        );
+
+        let cached = DayReport {
+            cached: true,
+            ..report
+        };
+        assert_eq!(
+            "Day 01 Results (input 0123456789abcdef, cached):\n\tPart 01: 3\n\tPart 02: 6",
+            cached.to_string()
+        );
+    }
+
+    #[test]
+    fn test_display_alloc_stats() {
+        let mut part = phase(Outcome::Answer("40".to_string()));
+        part.alloc = Some(AllocStats {
+            allocations: 3,
+            peak_bytes: 2048,
+        });
+
+        assert_eq!("40 [1ms] (3 allocs, peak 2.0 KiB)", part.to_string());
+    }
+}
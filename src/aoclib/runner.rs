@@ -1,51 +1,517 @@
+use crate::aoclib::alloc::{self, AllocStats};
+use crate::aoclib::cache;
+use crate::aoclib::cancel::CancelToken;
+use crate::aoclib::fetch;
+use crate::aoclib::generate::Rng;
+use crate::aoclib::input;
+use crate::aoclib::isolate::{self, PanicReport};
+use crate::aoclib::report::{DayReport, Outcome, PhaseReport};
+use crate::aoclib::scaling::Complexity;
+use std::collections::BTreeMap;
+use std::hint::black_box;
 use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, mpsc};
+use std::time::{Duration, Instant};
 
-pub trait Runner: FromStr {
-    type Output: std::fmt::Display;
+/// Settings that control how a batch of days is run.
+#[derive(Debug, Clone, Default)]
+pub struct RunOptions {
+    /// Wall clock limit for each part. Parts run on a worker thread when this is set.
+    ///
+    /// A part that overruns isn't stopped: its worker is detached and keeps running in the
+    /// background until the part returns, unless the part polls its [`CancelToken`]. As the
+    /// allocation counters are process wide, no phase reports allocation statistics while such
+    /// a worker is still running.
+    pub part_timeout: Option<Duration>,
+    /// Name of the solution variant to run, for parts that have one by that name.
+    pub variant: Option<String>,
+}
+
+/// Named puzzle parameters that differ between the examples and the real input, e.g. how many
+/// pairs Day08 connects.
+pub type Params = BTreeMap<String, String>;
+
+/// A named implementation of a part.
+pub type Variant<T> = (&'static str, fn(&T) -> <T as Runner>::Output);
+
+/// Receives the phases of a day one at a time, e.g. to benchmark them. `run` executes the phase
+/// once and can be called any number of times.
+pub trait PhaseVisitor {
+    fn phase(&mut self, name: &str, run: &mut dyn FnMut());
+}
+
+/// The answer of one variant of a part, from [`Runner::run_variants`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct VariantRun {
+    pub part: u32,
+    pub variant: &'static str,
+    pub report: PhaseReport,
+}
+
+/// How far along a part's solution is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartStatus {
+    Solved,
+    /// The part returns a hard coded value (e.g. the example answer) rather than solving it.
+    Placeholder,
+    /// The part isn't implemented yet.
+    Unsolved,
+}
+
+pub trait Runner: FromStr<Err: std::fmt::Debug> + Send + Sync + 'static {
+    type Output: std::fmt::Display + Send;
 
     /// Get the expected input path for the input file of a particular Runner.
     fn input_path(&self) -> String {
+        let (year, day) = self.name();
+        fetch::input_path(year, day)
+    }
+
+    /// Get the directory holding the day's examples, as `<name>.input` and `<name>.expected`.
+    fn examples_dir(&self) -> String {
         let (_, day) = self.name();
-        format!("input/problem/day{:02}.input", day)
+        format!("input/test/day{:02}", day)
+    }
+
+    /// Get the path of the sample input from the puzzle description.
+    fn test_input_path(&self) -> String {
+        format!("{}/example.input", self.examples_dir())
+    }
+
+    /// Whether blank lines at the end of the input should be dropped before parsing.
+    fn trim_trailing_blank_lines(&self) -> bool {
+        true
     }
 
     fn name(&self) -> (u32, u32);
+
+    /// The puzzle's title, e.g. `Secret Entrance` for 2025 day 1.
+    fn title(&self) -> Option<&'static str> {
+        None
+    }
+
     fn part01(&self) -> Self::Output;
     fn part02(&self) -> Self::Output;
 
-    fn run(&self) -> String {
-        let start_time = std::time::Instant::now();
+    fn part_status(&self, _part: u32) -> PartStatus {
+        PartStatus::Solved
+    }
+
+    /// The implementations of a part, by name. The first one is the default, and should be the
+    /// one `part01`/`part02` use. Days with alternative solutions (e.g. a brute force and a fast
+    /// one) list them all here so they can be selected, cross checked and benchmarked.
+    fn variants(&self, part: u32) -> Vec<Variant<Self>> {
+        match part {
+            1 => vec![("default", Self::part01)],
+            _ => vec![("default", Self::part02)],
+        }
+    }
+
+    /// The implementation of `part` named by `options.variant`, or the default one.
+    fn select_variant(&self, part: u32, options: &RunOptions) -> fn(&Self) -> Self::Output {
+        let variants = self.variants(part);
+        options
+            .variant
+            .as_deref()
+            .and_then(|name| variants.iter().find(|(n, _)| *n == name))
+            .unwrap_or(&variants[0])
+            .1
+    }
+
+    /// Generate a random, structurally valid input of roughly `size` items for stress tests and
+    /// scaling benchmarks, or `None` for days without a generator.
+    fn generate(&self, _size: usize, _rng: &mut Rng) -> Option<String> {
+        None
+    }
+
+    /// The complexity class of a part in the input length, checked by scaling benchmarks.
+    fn complexity(&self, _part: u32) -> Option<Complexity> {
+        None
+    }
+
+    /// Apply puzzle parameters to the parsed input. Days without parameters reject any.
+    fn apply_params(&mut self, params: &Params) -> Result<(), String> {
+        match params.keys().next() {
+            Some(key) => Err(format!("Unknown puzzle parameter '{}'", key)),
+            None => Ok(()),
+        }
+    }
+
+    /// Normalise and parse raw input, independent of line endings or a byte order mark.
+    fn parse_input(&self, raw: &str) -> Result<Self, Self::Err> {
+        Self::from_str(&input::normalize(raw, self.trim_trailing_blank_lines()))
+    }
+
+    fn run(&self) -> DayReport {
+        self.run_with(&RunOptions::default())
+    }
+
+    /// Read, parse and solve the day's input. Every phase runs under panic isolation, so a
+    /// panicking solution is reported as a failure instead of aborting the whole batch.
+    fn run_with(&self, options: &RunOptions) -> DayReport {
         let input_path = self.input_path();
-        let input = std::fs::read_to_string(&input_path)
-            .unwrap_or_else(|e| panic!("Failed to read input file {}: {}", input_path, e));
-        let parsed = Self::from_str(&input)
-            .map_err(|_| "Parse error".to_string())
-            .unwrap_or_else(|e| panic!("Failed to parse input: {}", e));
-        let parse_duration = start_time.elapsed();
-
-        let part1_result = parsed.part01();
-        let part1_duration = start_time.elapsed() - parse_duration;
-        let part2_result = parsed.part02();
-        let part2_duration = start_time.elapsed() - parse_duration - part1_duration;
-
-        format!(
-            "Day {:02} Results:\n\tParse: [{:?}]\n\tPart 01: {} [{:?}]\n\tPart 02: {} [{:?}]",
-            self.name().1,
-            parse_duration,
-            part1_result,
-            part1_duration,
-            part2_result,
-            part2_duration
+        let error = match input::load(&input_path) {
+            Ok(Some(input)) => return self.run_input(&input, &Params::new(), options),
+            Ok(None) => format!("Input file {} not found", input_path),
+            Err(e) => e,
+        };
+        let (year, day) = self.name();
+        DayReport::parse_failed(year, day, PhaseReport::failed(error))
+    }
+
+    /// Parse and solve the given raw input, as [`Runner::run_with`] does for the input file.
+    fn run_input(&self, input: &str, params: &Params, options: &RunOptions) -> DayReport {
+        let (year, day) = self.name();
+        let fingerprint = cache::fingerprint(input);
+
+        let (parsed, parse) = match self.parse_phase(input, params) {
+            Ok(parsed) => parsed,
+            Err(parse) => {
+                return DayReport::parse_failed(year, day, parse).with_fingerprint(fingerprint);
+            }
+        };
+
+        let parsed = Arc::new(parsed);
+        let part01 = parsed.select_variant(1, options);
+        let part02 = parsed.select_variant(2, options);
+        DayReport::new(
+            year,
+            day,
+            parse,
+            Some(run_part(&parsed, part01, options.part_timeout)),
+            Some(run_part(&parsed, part02, options.part_timeout)),
         )
+        .with_fingerprint(fingerprint)
+    }
+
+    /// Parse the given raw input and run every variant of both parts on it. Returns the parse
+    /// phase when parsing fails.
+    fn run_variants(
+        &self,
+        input: &str,
+        params: &Params,
+        options: &RunOptions,
+    ) -> Result<Vec<VariantRun>, PhaseReport> {
+        let (parsed, _) = self.parse_phase(input, params)?;
+        let parsed = Arc::new(parsed);
+        let mut runs = vec![];
+        for part in [1, 2] {
+            for (variant, solve) in parsed.variants(part) {
+                runs.push(VariantRun {
+                    part,
+                    variant,
+                    report: run_part(&parsed, solve, options.part_timeout),
+                });
+            }
+        }
+        Ok(runs)
+    }
+
+    /// Parse raw input and apply parameters under panic isolation.
+    fn parse_phase(
+        &self,
+        input: &str,
+        params: &Params,
+    ) -> Result<(Self, PhaseReport), PhaseReport> {
+        let (parsed, parse_duration, parse_alloc) = timed(|| {
+            let mut parsed = self
+                .parse_input(input)
+                .map_err(|e| format!("Failed to parse input: {:?}", e))?;
+            parsed.apply_params(params)?;
+            Ok(parsed)
+        });
+        let phase = |outcome| PhaseReport {
+            outcome,
+            duration: parse_duration,
+            alloc: parse_alloc,
+        };
+        match parsed
+            .map_err(Outcome::Panicked)
+            .and_then(|result| result.map_err(Outcome::Failed))
+        {
+            Ok(parsed) => Ok((parsed, phase(Outcome::Answer(String::new())))),
+            Err(outcome) => Err(phase(outcome)),
+        }
+    }
+}
+
+/// Workers whose part timed out and is still running. Their allocations would count towards
+/// whatever phase is being measured, see [`RunOptions::part_timeout`].
+static ABANDONED_WORKERS: AtomicUsize = AtomicUsize::new(0);
+
+/// Run a phase under panic isolation, measuring its duration and allocations.
+fn timed<T>(f: impl FnOnce() -> T) -> (Result<T, PanicReport>, Duration, Option<AllocStats>) {
+    let start_time = Instant::now();
+    let abandoned = ABANDONED_WORKERS.load(Ordering::SeqCst);
+    let (result, alloc) = alloc::measure(|| isolate::catch(f));
+    let alloc = alloc.filter(|_| abandoned == 0 && ABANDONED_WORKERS.load(Ordering::SeqCst) == 0);
+    (result, start_time.elapsed(), alloc)
+}
+
+/// Run a single part, on a worker thread with a deadline when `timeout` is set. A part that
+/// overruns has its [`CancelToken`] cancelled and is left to finish in the background.
+fn run_part<T: Runner>(
+    parsed: &Arc<T>,
+    part: fn(&T) -> T::Output,
+    timeout: Option<Duration>,
+) -> PhaseReport {
+    let Some(timeout) = timeout else {
+        let (result, duration, alloc) = timed(|| part(parsed));
+        return phase_report(result, duration, alloc);
+    };
+
+    let token = CancelToken::new();
+    let (sender, receiver) = mpsc::channel();
+    let worker_parsed = Arc::clone(parsed);
+    let worker_token = token.clone();
+    // Set by whichever comes first of the part finishing and the part timing out. The second
+    // one to get there takes the worker off the abandoned count.
+    let settled = Arc::new(AtomicBool::new(false));
+    let worker_settled = Arc::clone(&settled);
+    std::thread::spawn(move || {
+        let (result, duration, alloc) = timed(|| worker_token.scope(|| part(&worker_parsed)));
+        if worker_settled.swap(true, Ordering::SeqCst) {
+            ABANDONED_WORKERS.fetch_sub(1, Ordering::SeqCst);
+        }
+        // The receiver is gone if the part already timed out.
+        let _ = sender.send((result, duration, alloc));
+    });
+
+    match receiver.recv_timeout(timeout) {
+        Ok((result, duration, alloc)) => phase_report(result, duration, alloc),
+        Err(_) => {
+            ABANDONED_WORKERS.fetch_add(1, Ordering::SeqCst);
+            if settled.swap(true, Ordering::SeqCst) {
+                ABANDONED_WORKERS.fetch_sub(1, Ordering::SeqCst);
+            }
+            token.cancel();
+            PhaseReport {
+                outcome: Outcome::TimedOut(timeout),
+                duration: timeout,
+                alloc: None,
+            }
+        }
+    }
+}
+
+fn phase_report<O: std::fmt::Display>(
+    result: Result<O, PanicReport>,
+    duration: Duration,
+    alloc: Option<AllocStats>,
+) -> PhaseReport {
+    let outcome = match result {
+        Ok(answer) => Outcome::Answer(answer.to_string()),
+        Err(panic) => Outcome::Panicked(panic),
+    };
+    PhaseReport {
+        outcome,
+        duration,
+        alloc,
     }
 }
 
 pub trait AocDay {
-    fn run_day(&self) -> String;
+    fn year_day(&self) -> (u32, u32);
+    fn title(&self) -> Option<&'static str>;
+    fn input_path(&self) -> String;
+    fn examples_dir(&self) -> String;
+    fn test_input_path(&self) -> String;
+    fn part_status(&self, part: u32) -> PartStatus;
+    fn variant_names(&self, part: u32) -> Vec<&'static str>;
+    fn generate(&self, size: usize, seed: u64) -> Option<String>;
+    fn complexity(&self, part: u32) -> Option<Complexity>;
+    fn run_day(&self, options: &RunOptions) -> DayReport;
+    fn run_input(&self, input: &str, params: &Params, options: &RunOptions) -> DayReport;
+    fn run_variants(
+        &self,
+        input: &str,
+        params: &Params,
+        options: &RunOptions,
+    ) -> Result<Vec<VariantRun>, PhaseReport>;
+
+    /// Parse `input` and solve both parts, returning the answers as strings.
+    fn solve(&self, input: &str) -> Result<(String, String), String>;
+
+    /// Hand the parse phase of `input` and every variant of both parts to `visitor`, named
+    /// `parse` and `partNN/<variant>`. Fails if `input` doesn't parse.
+    fn visit_phases(&self, input: &str, visitor: &mut dyn PhaseVisitor) -> Result<(), String>;
 }
 
 impl<T: Runner> AocDay for T {
-    fn run_day(&self) -> String {
-        self.run()
+    fn year_day(&self) -> (u32, u32) {
+        self.name()
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Runner::title(self)
+    }
+
+    fn input_path(&self) -> String {
+        Runner::input_path(self)
+    }
+
+    fn examples_dir(&self) -> String {
+        Runner::examples_dir(self)
+    }
+
+    fn test_input_path(&self) -> String {
+        Runner::test_input_path(self)
+    }
+
+    fn part_status(&self, part: u32) -> PartStatus {
+        Runner::part_status(self, part)
+    }
+
+    fn variant_names(&self, part: u32) -> Vec<&'static str> {
+        Runner::variants(self, part)
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect()
+    }
+
+    fn generate(&self, size: usize, seed: u64) -> Option<String> {
+        Runner::generate(self, size, &mut Rng::new(seed))
+    }
+
+    fn complexity(&self, part: u32) -> Option<Complexity> {
+        Runner::complexity(self, part)
+    }
+
+    fn run_day(&self, options: &RunOptions) -> DayReport {
+        self.run_with(options)
+    }
+
+    fn run_input(&self, input: &str, params: &Params, options: &RunOptions) -> DayReport {
+        Runner::run_input(self, input, params, options)
+    }
+
+    fn run_variants(
+        &self,
+        input: &str,
+        params: &Params,
+        options: &RunOptions,
+    ) -> Result<Vec<VariantRun>, PhaseReport> {
+        Runner::run_variants(self, input, params, options)
+    }
+
+    fn solve(&self, input: &str) -> Result<(String, String), String> {
+        let parsed = self
+            .parse_input(input)
+            .map_err(|e| format!("Parse error: {:?}", e))?;
+        Ok((parsed.part01().to_string(), parsed.part02().to_string()))
+    }
+
+    fn visit_phases(&self, input: &str, visitor: &mut dyn PhaseVisitor) -> Result<(), String> {
+        let parsed = self
+            .parse_input(input)
+            .map_err(|e| format!("Parse error: {:?}", e))?;
+        visitor.phase("parse", &mut || {
+            let _ = black_box(self.parse_input(black_box(input)));
+        });
+        for part in [1, 2] {
+            for (variant, solve) in parsed.variants(part) {
+                visitor.phase(&format!("part{:02}/{}", part, variant), &mut || {
+                    black_box(solve(black_box(&parsed)));
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoclib::cancel::CancelToken;
+    use crate::aoclib::report::Outcome;
+    use crate::aoclib::runner::{ABANDONED_WORKERS, PhaseVisitor, RunOptions, Runner};
+    use crate::registry;
+    use std::str::FromStr;
+    use std::sync::atomic::Ordering;
+    use std::time::Duration;
+
+    #[derive(Debug, Default)]
+    struct Stalling;
+
+    impl FromStr for Stalling {
+        type Err = String;
+
+        fn from_str(_s: &str) -> Result<Self, Self::Err> {
+            Ok(Stalling)
+        }
+    }
+
+    impl Runner for Stalling {
+        type Output = u32;
+
+        fn input_path(&self) -> String {
+            "input/test/day01/example.input".to_string()
+        }
+
+        fn name(&self) -> (u32, u32) {
+            (2025, 99)
+        }
+
+        fn part01(&self) -> Self::Output {
+            1
+        }
+
+        fn part02(&self) -> Self::Output {
+            let token = CancelToken::current();
+            while !token.is_cancelled() {
+                std::thread::sleep(Duration::from_millis(1));
+            }
+            2
+        }
+    }
+
+    #[test]
+    fn test_run_with_timeout() {
+        let options = RunOptions {
+            part_timeout: Some(Duration::from_millis(50)),
+            ..RunOptions::default()
+        };
+        let report = Stalling.run_with(&options);
+
+        assert_eq!(Some("1"), report.part01.unwrap().answer());
+        assert_eq!(
+            Outcome::TimedOut(Duration::from_millis(50)),
+            report.part02.unwrap().outcome
+        );
+
+        // The cancelled part returns soon after, and stops counting as abandoned.
+        for _ in 0..100 {
+            if ABANDONED_WORKERS.load(Ordering::SeqCst) == 0 {
+                return;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        panic!("The timed out worker is still counted as abandoned");
+    }
+
+    #[test]
+    fn test_visit_phases() {
+        struct Names(Vec<String>);
+        impl PhaseVisitor for Names {
+            fn phase(&mut self, name: &str, run: &mut dyn FnMut()) {
+                run();
+                self.0.push(name.to_string());
+            }
+        }
+
+        let day = registry::find(Some(2025), 2).unwrap();
+        let mut names = Names(vec![]);
+        day.visit_phases("11-22", &mut names).unwrap();
+        assert_eq!(
+            vec![
+                "parse",
+                "part01/fast",
+                "part01/slow",
+                "part02/fast",
+                "part02/slow"
+            ],
+            names.0
+        );
+        assert!(day.visit_phases("11-", &mut names).is_err());
     }
 }
@@ -0,0 +1,171 @@
+use crate::aoclib::generate;
+use crate::aoclib::report::DayReport;
+use crate::aoclib::runner::{AocDay, Params, RunOptions};
+use std::fmt;
+use std::time::Duration;
+
+/// How much slower than its declared class a part may grow before the check fails. Leaves room
+/// for log factors and timing noise.
+pub const EXPONENT_TOLERANCE: f64 = 0.5;
+
+/// A complexity class a day declares for a part, in terms of the input length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Complexity {
+    Linear,
+    Linearithmic,
+    Quadratic,
+    QuadraticLog,
+    Cubic,
+}
+
+impl Complexity {
+    /// The polynomial exponent of the class, ignoring log factors.
+    pub fn exponent(&self) -> f64 {
+        match self {
+            Complexity::Linear | Complexity::Linearithmic => 1.0,
+            Complexity::Quadratic | Complexity::QuadraticLog => 2.0,
+            Complexity::Cubic => 3.0,
+        }
+    }
+
+    /// Whether a fitted growth exponent is consistent with this class.
+    pub fn allows(&self, exponent: f64) -> bool {
+        exponent <= self.exponent() + EXPONENT_TOLERANCE
+    }
+}
+
+impl fmt::Display for Complexity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let class = match self {
+            Complexity::Linear => "O(n)",
+            Complexity::Linearithmic => "O(n log n)",
+            Complexity::Quadratic => "O(n^2)",
+            Complexity::QuadraticLog => "O(n^2 log n)",
+            Complexity::Cubic => "O(n^3)",
+        };
+        f.write_str(class)
+    }
+}
+
+/// The fastest of several runs of a day on one generated input.
+#[derive(Debug, Clone)]
+pub struct Sample {
+    pub size: usize,
+    /// Length of the generated input, the `n` exponents are fitted against.
+    pub bytes: usize,
+    pub parse: Duration,
+    pub part01: Option<Duration>,
+    pub part02: Option<Duration>,
+}
+
+/// Run `day` on generated inputs of each of `sizes`, keeping the fastest of `repeats` runs of
+/// every phase. Fails if the day has no generator or a run doesn't produce answers.
+pub fn measure(
+    day: &dyn AocDay,
+    sizes: &[usize],
+    seed: u64,
+    repeats: usize,
+    options: &RunOptions,
+) -> Result<Vec<Sample>, String> {
+    let mut samples = vec![];
+    for &size in sizes {
+        let input = generate::generate(day, size, seed)?;
+        let mut sample = Sample {
+            size,
+            bytes: input.len(),
+            parse: Duration::MAX,
+            part01: None,
+            part02: None,
+        };
+        for _ in 0..repeats.max(1) {
+            let report = day.run_input(&input, &Params::new(), options);
+            if !report.is_ok() {
+                return Err(format!(
+                    "Failed on a generated input of size {}:\n{}",
+                    size, report
+                ));
+            }
+            sample.parse = sample.parse.min(report.parse.duration);
+            sample.part01 = fastest(sample.part01, &report, 1);
+            sample.part02 = fastest(sample.part02, &report, 2);
+        }
+        samples.push(sample);
+    }
+    Ok(samples)
+}
+
+fn fastest(current: Option<Duration>, report: &DayReport, part: u32) -> Option<Duration> {
+    let phase = match part {
+        1 => report.part01.as_ref(),
+        _ => report.part02.as_ref(),
+    };
+    let duration = phase?.duration;
+    Some(current.map_or(duration, |current| current.min(duration)))
+}
+
+/// The slope of the least squares line through `(ln n, ln t)`, i.e. `k` in `t ~ n^k`. `None`
+/// without at least two distinct, non-zero points.
+pub fn fit_exponent(points: &[(f64, f64)]) -> Option<f64> {
+    let logs: Vec<(f64, f64)> = points
+        .iter()
+        .filter(|(n, t)| *n > 0.0 && *t > 0.0)
+        .map(|(n, t)| (n.ln(), t.ln()))
+        .collect();
+    let count = logs.len() as f64;
+    let mean_x = logs.iter().map(|(x, _)| x).sum::<f64>() / count;
+    let mean_y = logs.iter().map(|(_, y)| y).sum::<f64>() / count;
+    let covariance: f64 = logs.iter().map(|(x, y)| (x - mean_x) * (y - mean_y)).sum();
+    let variance: f64 = logs.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+    (logs.len() >= 2 && variance > 0.0).then(|| covariance / variance)
+}
+
+/// The growth exponent of one phase over `samples`.
+pub fn phase_exponent(
+    samples: &[Sample],
+    phase: impl Fn(&Sample) -> Option<Duration>,
+) -> Option<f64> {
+    let points: Vec<(f64, f64)> = samples
+        .iter()
+        .filter_map(|sample| Some((sample.bytes as f64, phase(sample)?.as_secs_f64())))
+        .collect();
+    fit_exponent(&points)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoclib::runner::RunOptions;
+    use crate::aoclib::scaling::{Complexity, fit_exponent, measure, phase_exponent};
+    use crate::registry;
+
+    #[test]
+    fn test_fit_exponent() {
+        let quadratic: Vec<(f64, f64)> =
+            (1..=5).map(|n| (n as f64, 3.0 * (n * n) as f64)).collect();
+        assert!((fit_exponent(&quadratic).unwrap() - 2.0).abs() < 1e-9);
+
+        let linear = [(100.0, 0.5), (200.0, 1.0), (400.0, 2.0)];
+        assert!((fit_exponent(&linear).unwrap() - 1.0).abs() < 1e-9);
+
+        assert_eq!(None, fit_exponent(&[(100.0, 0.5)]));
+        assert_eq!(None, fit_exponent(&[(100.0, 0.5), (100.0, 0.7)]));
+    }
+
+    #[test]
+    fn test_complexity_allows() {
+        assert!(Complexity::Linear.allows(1.2));
+        assert!(!Complexity::Linear.allows(2.0));
+        assert!(Complexity::QuadraticLog.allows(2.2));
+        assert_eq!("O(n^2 log n)", Complexity::QuadraticLog.to_string());
+    }
+
+    #[test]
+    fn test_measure() {
+        let day = registry::find(Some(2025), 1).unwrap();
+        let samples = measure(day.as_ref(), &[10, 20, 40], 0, 2, &RunOptions::default()).unwrap();
+
+        assert_eq!(3, samples.len());
+        assert!(samples.windows(2).all(|pair| pair[0].bytes < pair[1].bytes));
+        assert!(samples.iter().all(|sample| sample.part01.is_some()));
+        assert!(phase_exponent(&samples, |sample| sample.part01).is_some());
+    }
+}
@@ -0,0 +1,509 @@
+use crate::aoclib::client::AocClient;
+use crate::aoclib::runner::PartStatus;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Default location of the attempt log, which also serves as the record of accepted answers.
+pub const ATTEMPT_LOG_PATH: &str = "input/answers.tsv";
+
+/// The site's response to a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    RateLimited,
+    /// The part was already solved, so the answer wasn't checked.
+    AlreadySolved,
+    Unknown,
+}
+
+impl Verdict {
+    /// Classify the HTML page returned after posting an answer.
+    pub fn from_response(body: &str) -> Self {
+        if body.contains("That's the right answer") {
+            Verdict::Correct
+        } else if body.contains("You gave an answer too recently") {
+            Verdict::RateLimited
+        } else if body.contains("You don't seem to be solving the right level") {
+            Verdict::AlreadySolved
+        } else if body.contains("That's not the right answer") {
+            if body.contains("your answer is too high") {
+                Verdict::TooHigh
+            } else if body.contains("your answer is too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            }
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    /// Whether the verdict says anything about the answer itself.
+    fn is_judgement(&self) -> bool {
+        matches!(
+            self,
+            Verdict::Correct | Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow
+        )
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong => "wrong",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::RateLimited => "rate-limited",
+            Verdict::AlreadySolved => "already-solved",
+            Verdict::Unknown => "unknown",
+        };
+        f.write_str(name)
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "wrong" => Ok(Verdict::Wrong),
+            "too-high" => Ok(Verdict::TooHigh),
+            "too-low" => Ok(Verdict::TooLow),
+            "rate-limited" => Ok(Verdict::RateLimited),
+            "already-solved" => Ok(Verdict::AlreadySolved),
+            "unknown" => Ok(Verdict::Unknown),
+            _ => Err(format!("Invalid verdict: '{}'", s)),
+        }
+    }
+}
+
+/// A single submitted answer, stored as a tab separated line
+/// `year day part answer verdict timestamp`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attempt {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+}
+
+impl FromStr for Attempt {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = s.split('\t').collect();
+        let [year, day, part, answer, verdict, timestamp] = fields[..] else {
+            return Err(format!("Expected 6 tab separated fields: '{}'", s));
+        };
+        let number = |field: &str| {
+            field
+                .parse::<u64>()
+                .map_err(|_| format!("Invalid number '{}' in attempt: '{}'", field, s))
+        };
+        Ok(Attempt {
+            year: number(year)? as u32,
+            day: number(day)? as u32,
+            part: number(part)? as u32,
+            answer: answer.to_string(),
+            verdict: verdict.parse()?,
+            timestamp: number(timestamp)?,
+        })
+    }
+}
+
+impl fmt::Display for Attempt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\t{}\t{}\t{}",
+            self.year, self.day, self.part, self.answer, self.verdict, self.timestamp
+        )
+    }
+}
+
+/// Every answer ever submitted, used to avoid resubmitting answers already known to be wrong.
+#[derive(Debug)]
+pub struct AttemptLog {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl AttemptLog {
+    /// Load the log, treating a missing file as an empty log.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref().to_path_buf();
+        let attempts = match std::fs::read_to_string(&path) {
+            Ok(contents) => contents
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .map(str::parse)
+                .collect::<Result<Vec<Attempt>, String>>()?,
+            Err(_) => vec![],
+        };
+        Ok(AttemptLog { path, attempts })
+    }
+
+    /// Append an attempt to the log file.
+    pub fn record(&mut self, attempt: Attempt) -> Result<(), String> {
+        use std::io::Write;
+
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+        }
+        let write_error =
+            |e: std::io::Error| format!("Failed to write {}: {}", self.path.display(), e);
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(write_error)?;
+        writeln!(file, "{}", attempt).map_err(write_error)?;
+        self.attempts.push(attempt);
+        Ok(())
+    }
+
+    pub fn attempts(&self, year: u32, day: u32, part: u32) -> impl Iterator<Item = &Attempt> {
+        self.attempts
+            .iter()
+            .filter(move |a| (a.year, a.day, a.part) == (year, day, part))
+    }
+
+    /// The accepted answer for a part, if there is one.
+    pub fn correct_answer(&self, year: u32, day: u32, part: u32) -> Option<&str> {
+        self.attempts(year, day, part)
+            .find(|a| a.verdict == Verdict::Correct)
+            .map(|a| a.answer.as_str())
+    }
+
+    /// Decide whether `answer` is worth submitting, based on earlier attempts. Returns the
+    /// reason when the outcome is already known.
+    pub fn precheck(&self, year: u32, day: u32, part: u32, answer: &str) -> Result<(), String> {
+        if let Some(correct) = self.correct_answer(year, day, part) {
+            return Err(if correct == answer {
+                format!("{} was already accepted", answer)
+            } else {
+                format!("{} differs from the accepted answer {}", answer, correct)
+            });
+        }
+
+        let value = answer.parse::<i128>().ok();
+        for attempt in self.attempts(year, day, part) {
+            if !attempt.verdict.is_judgement() {
+                continue;
+            }
+            if attempt.answer == answer {
+                return Err(format!(
+                    "{} was already rejected ({})",
+                    answer, attempt.verdict
+                ));
+            }
+            let Some((value, previous)) = value.zip(attempt.answer.parse::<i128>().ok()) else {
+                continue;
+            };
+            match attempt.verdict {
+                Verdict::TooHigh if value >= previous => {
+                    return Err(format!("{} is too high, {} already was", answer, previous));
+                }
+                Verdict::TooLow if value <= previous => {
+                    return Err(format!("{} is too low, {} already was", answer, previous));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Check the answer a part produced (`None` if it failed or never ran) against the accepted
+    /// one.
+    pub fn verify(
+        &self,
+        (year, day, part): (u32, u32, u32),
+        status: PartStatus,
+        answer: Option<&str>,
+    ) -> Verification {
+        match (status, answer, self.correct_answer(year, day, part)) {
+            (PartStatus::Unsolved, _, _) => Verification::Unsolved,
+            (PartStatus::Placeholder, _, _) => Verification::Placeholder,
+            (PartStatus::Solved, None, _) => Verification::Failed,
+            (PartStatus::Solved, Some(answer), Some(correct)) if answer == correct => {
+                Verification::Verified
+            }
+            (PartStatus::Solved, Some(_), Some(_)) => Verification::Wrong,
+            (PartStatus::Solved, Some(_), None) => Verification::Unverified,
+        }
+    }
+}
+
+/// How a part's answer compares with the accepted answers in the attempt log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verification {
+    /// The answer matches the accepted one.
+    Verified,
+    /// The answer differs from the accepted one.
+    Wrong,
+    /// There is an answer, but none has been accepted yet.
+    Unverified,
+    /// The part produced no answer.
+    Failed,
+    Placeholder,
+    Unsolved,
+}
+
+impl fmt::Display for Verification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Verification::Verified => "verified",
+            Verification::Wrong => "wrong",
+            Verification::Unverified => "unverified",
+            Verification::Failed => "failed",
+            Verification::Placeholder => "placeholder",
+            Verification::Unsolved => "unsolved",
+        };
+        write!(f, "{}", label)
+    }
+}
+
+/// Submit an answer unless the part isn't solved or the log already knows its outcome, and
+/// record the attempt. A wrong answer locks the part out for a while, so placeholder and
+/// unsolved parts are never submitted.
+pub fn submit(
+    client: &mut AocClient,
+    log: &mut AttemptLog,
+    (year, day, part): (u32, u32, u32),
+    status: PartStatus,
+    answer: &str,
+) -> Result<Verdict, String> {
+    let status = match status {
+        PartStatus::Solved => None,
+        PartStatus::Placeholder => Some("a placeholder"),
+        PartStatus::Unsolved => Some("unsolved"),
+    };
+    if let Some(status) = status {
+        return Err(format!(
+            "{} day {:02} part {:02} is {}, so {} isn't worth submitting",
+            year, day, part, status, answer
+        ));
+    }
+    log.precheck(year, day, part, answer)?;
+
+    let level = part.to_string();
+    let response = client.post_form(
+        &format!("/{}/day/{}/answer", year, day),
+        &[("level", level.as_str()), ("answer", answer)],
+    )?;
+    if response.status != 200 {
+        return Err(format!(
+            "Unexpected status {} submitting {} day {} part {}",
+            response.status, year, day, part
+        ));
+    }
+
+    let verdict = Verdict::from_response(&response.body);
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    log.record(Attempt {
+        year,
+        day,
+        part,
+        answer: answer.to_string(),
+        verdict,
+        timestamp,
+    })?;
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoclib::client::{AocClient, ClientConfig};
+    use crate::aoclib::runner::PartStatus;
+    use crate::aoclib::submit::{Attempt, AttemptLog, Verdict, Verification, submit};
+    use crate::aoclib::test_server::TestServer;
+    use crate::registry;
+    use std::time::Duration;
+
+    fn attempt(part: u32, answer: &str, verdict: Verdict) -> Attempt {
+        Attempt {
+            year: 2025,
+            day: 1,
+            part,
+            answer: answer.to_string(),
+            verdict,
+            timestamp: 1764547200,
+        }
+    }
+
+    fn temp_log(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir()
+            .join(format!("aoc-submit-{}", std::process::id()))
+            .join(name)
+    }
+
+    #[test]
+    fn test_verdict_from_response() {
+        let page = |text: &str| format!("<main><article><p>{}</p></article></main>", text);
+        assert_eq!(
+            Verdict::Correct,
+            Verdict::from_response(&page(
+                "That's the right answer! You are one gold star closer."
+            ))
+        );
+        assert_eq!(
+            Verdict::TooHigh,
+            Verdict::from_response(&page(
+                "That's not the right answer; your answer is too high."
+            ))
+        );
+        assert_eq!(
+            Verdict::TooLow,
+            Verdict::from_response(&page(
+                "That's not the right answer; your answer is too low."
+            ))
+        );
+        assert_eq!(
+            Verdict::Wrong,
+            Verdict::from_response(&page("That's not the right answer. If you're stuck..."))
+        );
+        assert_eq!(
+            Verdict::RateLimited,
+            Verdict::from_response(&page("You gave an answer too recently; you have 41s left."))
+        );
+        assert_eq!(
+            Verdict::AlreadySolved,
+            Verdict::from_response(&page("You don't seem to be solving the right level."))
+        );
+        assert_eq!(Verdict::Unknown, Verdict::from_response("<html></html>"));
+    }
+
+    #[test]
+    fn test_attempt_round_trip() {
+        let original = attempt(2, "6", Verdict::TooLow);
+        assert_eq!("2025\t1\t2\t6\ttoo-low\t1764547200", original.to_string());
+        assert_eq!(
+            Ok(original.clone()),
+            original.to_string().parse::<Attempt>()
+        );
+        assert!("2025\t1\t2\t6\tmaybe\t0".parse::<Attempt>().is_err());
+        assert!("2025\t1\t2".parse::<Attempt>().is_err());
+    }
+
+    #[test]
+    fn test_precheck() {
+        let path = temp_log("precheck.tsv");
+        let _ = std::fs::remove_file(&path);
+        let mut log = AttemptLog::load(&path).unwrap();
+        log.record(attempt(1, "100", Verdict::TooHigh)).unwrap();
+        log.record(attempt(1, "10", Verdict::TooLow)).unwrap();
+        log.record(attempt(1, "50", Verdict::Wrong)).unwrap();
+        log.record(attempt(1, "40", Verdict::RateLimited)).unwrap();
+
+        assert!(log.precheck(2025, 1, 1, "100").is_err());
+        assert!(log.precheck(2025, 1, 1, "120").is_err());
+        assert!(log.precheck(2025, 1, 1, "5").is_err());
+        assert!(log.precheck(2025, 1, 1, "50").is_err());
+        assert!(log.precheck(2025, 1, 1, "40").is_ok());
+        assert!(log.precheck(2025, 1, 1, "60").is_ok());
+        assert!(log.precheck(2025, 1, 2, "100").is_ok());
+
+        log.record(attempt(1, "42", Verdict::Correct)).unwrap();
+        assert!(log.precheck(2025, 1, 1, "42").is_err());
+        assert!(log.precheck(2025, 1, 1, "60").is_err());
+
+        let reloaded = AttemptLog::load(&path).unwrap();
+        assert_eq!(Some("42"), reloaded.correct_answer(2025, 1, 1));
+        assert_eq!(5, reloaded.attempts(2025, 1, 1).count());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_verify() {
+        let path = temp_log("verify.tsv");
+        let _ = std::fs::remove_file(&path);
+        let mut log = AttemptLog::load(&path).unwrap();
+        log.record(attempt(1, "42", Verdict::Correct)).unwrap();
+
+        let verify = |part, status, answer| log.verify((2025, 1, part), status, answer);
+        assert_eq!(
+            Verification::Verified,
+            verify(1, PartStatus::Solved, Some("42"))
+        );
+        assert_eq!(
+            Verification::Wrong,
+            verify(1, PartStatus::Solved, Some("41"))
+        );
+        assert_eq!(Verification::Failed, verify(1, PartStatus::Solved, None));
+        assert_eq!(
+            Verification::Unverified,
+            verify(2, PartStatus::Solved, Some("7"))
+        );
+        assert_eq!(
+            Verification::Placeholder,
+            verify(2, PartStatus::Placeholder, Some("7"))
+        );
+        assert_eq!(
+            Verification::Unsolved,
+            verify(2, PartStatus::Unsolved, None)
+        );
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_submit() {
+        let server = TestServer::start(|request| match request.body.as_str() {
+            "level=1&answer=3" => (200, "<p>That's the right answer!</p>".to_string()),
+            _ => (
+                200,
+                "<p>That's not the right answer; your answer is too low.</p>".to_string(),
+            ),
+        });
+        let mut client = AocClient::new(ClientConfig {
+            base_url: server.url(),
+            session: Some("abc".to_string()),
+            throttle: Duration::ZERO,
+            last_request_path: None,
+        });
+        let path = temp_log("submit.tsv");
+        let _ = std::fs::remove_file(&path);
+        let mut log = AttemptLog::load(&path).unwrap();
+
+        assert_eq!(
+            Ok(Verdict::TooLow),
+            submit(&mut client, &mut log, (2025, 1, 1), PartStatus::Solved, "2")
+        );
+        assert!(submit(&mut client, &mut log, (2025, 1, 1), PartStatus::Solved, "1").is_err());
+        assert_eq!(
+            Ok(Verdict::Correct),
+            submit(&mut client, &mut log, (2025, 1, 1), PartStatus::Solved, "3")
+        );
+        assert!(submit(&mut client, &mut log, (2025, 1, 1), PartStatus::Solved, "3").is_err());
+
+        // Day 09 part 2 returns a hard-coded answer and Day 07 part 2 is a stub.
+        let status = |day| registry::find(Some(2025), day).unwrap().part_status(2);
+        let placeholder = submit(&mut client, &mut log, (2025, 9, 2), status(9), "24");
+        assert!(placeholder.unwrap_err().contains("placeholder"));
+        let unsolved = submit(&mut client, &mut log, (2025, 7, 2), status(7), "0");
+        assert!(unsolved.unwrap_err().contains("unsolved"));
+
+        assert_eq!(2, server.requests().len());
+        assert_eq!("/2025/day/1/answer", server.requests()[0].path);
+        assert_eq!(
+            2,
+            AttemptLog::load(&path)
+                .unwrap()
+                .attempts(2025, 1, 1)
+                .count()
+        );
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,305 @@
+use crate::aoclib::crypto;
+use crate::aoclib::examples;
+use crate::aoclib::report::{DayReport, Outcome, PhaseReport};
+use crate::aoclib::runner::{AocDay, RunOptions};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// Polls a set of files for changes to their modification time, including files that are
+/// created or deleted between polls.
+#[derive(Debug)]
+pub struct FileWatcher {
+    files: Vec<(PathBuf, Option<SystemTime>)>,
+}
+
+impl FileWatcher {
+    pub fn new(paths: impl IntoIterator<Item = PathBuf>) -> Self {
+        let files = paths
+            .into_iter()
+            .map(|path| {
+                let modified = modified(&path);
+                (path, modified)
+            })
+            .collect();
+        FileWatcher { files }
+    }
+
+    /// The files that changed since the previous poll.
+    pub fn changed(&mut self) -> Vec<PathBuf> {
+        self.files
+            .iter_mut()
+            .filter_map(|(path, last_modified)| {
+                let modified = modified(path);
+                if modified == *last_modified {
+                    return None;
+                }
+                *last_modified = modified;
+                Some(path.clone())
+            })
+            .collect()
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+/// One line of a watch run: a phase, its answer (or failure) and how long it took.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Row {
+    pub label: String,
+    pub value: String,
+    pub duration: Duration,
+}
+
+fn phase_value(phase: &PhaseReport) -> String {
+    match &phase.outcome {
+        Outcome::Answer(answer) => answer.clone(),
+        Outcome::Failed(error) => format!("FAILED: {}", error),
+        Outcome::Panicked(panic) => format!("FAILED: {}", panic),
+        Outcome::TimedOut(limit) => format!("TIMED OUT after {:?}", limit),
+    }
+}
+
+fn rows(prefix: &str, report: &DayReport) -> Vec<Row> {
+    let parse_value = match &report.parse.outcome {
+        Outcome::Answer(_) => "ok".to_string(),
+        _ => phase_value(&report.parse),
+    };
+    let mut rows = vec![Row {
+        label: format!("{}parse", prefix),
+        value: parse_value,
+        duration: report.parse.duration,
+    }];
+    for (name, part) in [("part01", &report.part01), ("part02", &report.part02)] {
+        if let Some(part) = part {
+            rows.push(Row {
+                label: format!("{}{}", prefix, name),
+                value: phase_value(part),
+                duration: part.duration,
+            });
+        }
+    }
+    rows
+}
+
+/// Run the day on its input and on every example, checking the examples' expected answers.
+pub fn snapshot(day: &dyn AocDay, options: &RunOptions) -> Vec<Row> {
+    let mut snapshot = rows("", &day.run_day(options));
+
+    let examples = match examples::load(day.examples_dir()) {
+        Ok(examples) => examples,
+        Err(e) => {
+            snapshot.push(Row {
+                label: "examples".to_string(),
+                value: format!("FAILED: {}", e),
+                duration: Duration::ZERO,
+            });
+            return snapshot;
+        }
+    };
+    for example in examples {
+        let report = match example.run(day, options) {
+            Ok(report) => report,
+            Err(e) => {
+                snapshot.push(Row {
+                    label: example.name.clone(),
+                    value: format!("FAILED: {}", e),
+                    duration: Duration::ZERO,
+                });
+                continue;
+            }
+        };
+        let mut example_rows = rows(&format!("{} ", example.name), &report);
+        for (row, part) in example_rows.iter_mut().skip(1).zip(1..) {
+            if let Some(expected) = example.expected.part(part) {
+                let check = match example.verify(&report, part) {
+                    Ok(()) => "ok".to_string(),
+                    Err(_) => format!("expected {}", expected),
+                };
+                row.value = format!("{} ({})", row.value, check);
+            }
+        }
+        snapshot.extend(example_rows);
+    }
+    snapshot
+}
+
+/// Render a run as a compact diff against the previous one. Rows whose answer changed are
+/// marked with `~` and new rows with `+`; timings show the change relative to the last run.
+pub fn render(previous: Option<&[Row]>, current: &[Row]) -> String {
+    let mut output = String::new();
+    for row in current {
+        let before = previous.and_then(|rows| rows.iter().find(|r| r.label == row.label));
+        let (marker, value) = match before {
+            Some(before) if before.value != row.value => {
+                ('~', format!("{} -> {}", before.value, row.value))
+            }
+            Some(_) => (' ', row.value.clone()),
+            None if previous.is_some() => ('+', row.value.clone()),
+            None => (' ', row.value.clone()),
+        };
+        let timing = match before {
+            Some(before) if !before.duration.is_zero() => format!(
+                "{:?} -> {:?} ({:+.0}%)",
+                before.duration,
+                row.duration,
+                (row.duration.as_secs_f64() / before.duration.as_secs_f64() - 1.0) * 100.0
+            ),
+            _ => format!("{:?}", row.duration),
+        };
+        let _ = writeln!(
+            output,
+            "{} {:<16} {:<24} [{}]",
+            marker, row.label, value, timing
+        );
+    }
+    output
+}
+
+/// The source file of a day's solution.
+pub fn source_path(year: u32, day: u32) -> PathBuf {
+    PathBuf::from(format!("src/aoc{}/day{:02}.rs", year, day))
+}
+
+/// The files a watch run depends on: the day's input and its encrypted copy, its examples
+/// directory (to notice new examples) and every example input and expectation file in it.
+fn watched_inputs(day: &dyn AocDay) -> Vec<PathBuf> {
+    let examples_dir = PathBuf::from(day.examples_dir());
+    let input_path = day.input_path();
+    let mut paths = vec![
+        PathBuf::from(crypto::encrypted_path(&input_path)),
+        PathBuf::from(input_path),
+        examples_dir.clone(),
+    ];
+    if let Ok(entries) = std::fs::read_dir(&examples_dir) {
+        let mut example_files: Vec<PathBuf> = entries.flatten().map(|e| e.path()).collect();
+        example_files.sort();
+        paths.extend(example_files);
+    }
+    paths
+}
+
+/// Re-run `day` whenever its input or examples change, printing each run as a diff
+/// against the previous one.
+///
+/// A running binary can't pick up source changes, so when the day's source file changes
+/// `on_source_change` is called instead, typically to rebuild and restart. If it returns an
+/// error (e.g. the rebuild failed) the error is printed and watching continues.
+pub fn watch(
+    day: &dyn AocDay,
+    options: &RunOptions,
+    interval: Duration,
+    mut on_source_change: impl FnMut(&Path) -> Result<(), String>,
+) -> ! {
+    let (year, day_num) = day.year_day();
+    let mut inputs = FileWatcher::new(watched_inputs(day));
+    let mut sources = FileWatcher::new([source_path(year, day_num)]);
+    let mut previous: Option<Vec<Row>> = None;
+    let mut changed: Vec<PathBuf> = vec![];
+    let mut run = 0;
+
+    loop {
+        run += 1;
+        let current = snapshot(day, options);
+        let reason = match changed.as_slice() {
+            [] => String::new(),
+            paths => format!(
+                " ({} changed)",
+                paths
+                    .iter()
+                    .map(|p| p.display().to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        };
+        println!("Day {:02} run #{}{}", day_num, run, reason);
+        print!("{}", render(previous.as_deref(), &current));
+        previous = Some(current);
+
+        changed = loop {
+            std::thread::sleep(interval);
+            if let Some(source) = sources.changed().first()
+                && let Err(e) = on_source_change(source)
+            {
+                eprintln!("{}", e);
+            }
+            let changed = inputs.changed();
+            if !changed.is_empty() {
+                // Pick up examples that were added or removed.
+                inputs = FileWatcher::new(watched_inputs(day));
+                break changed;
+            }
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoclib::watch::{FileWatcher, Row, render};
+    use std::time::Duration;
+
+    fn row(label: &str, value: &str, millis: u64) -> Row {
+        Row {
+            label: label.to_string(),
+            value: value.to_string(),
+            duration: Duration::from_millis(millis),
+        }
+    }
+
+    #[test]
+    fn test_render_first_run() {
+        let current = vec![row("part01", "21", 2)];
+        assert_eq!(
+            "  part01           21                       [2ms]\n",
+            render(None, &current)
+        );
+    }
+
+    #[test]
+    fn test_render_diff() {
+        let previous = vec![row("part01", "21", 2), row("part02", "0", 4)];
+        let current = vec![
+            row("part01", "21", 1),
+            row("part02", "40", 4),
+            row("example part01", "21", 1),
+        ];
+        let rendered = render(Some(&previous), &current);
+        let lines: Vec<&str> = rendered.lines().collect();
+
+        assert_eq!(
+            "  part01           21                       [2ms -> 1ms (-50%)]",
+            lines[0]
+        );
+        assert_eq!(
+            "~ part02           0 -> 40                  [4ms -> 4ms (+0%)]",
+            lines[1]
+        );
+        assert_eq!(
+            "+ example part01   21                       [1ms]",
+            lines[2]
+        );
+    }
+
+    #[test]
+    fn test_file_watcher() {
+        let dir = std::env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("day01.input");
+        let _ = std::fs::remove_file(&path);
+
+        let mut watcher = FileWatcher::new([path.clone()]);
+        assert!(watcher.changed().is_empty());
+
+        std::fs::write(&path, "L68\n").unwrap();
+        assert_eq!(vec![path.clone()], watcher.changed());
+        assert!(watcher.changed().is_empty());
+
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(vec![path], watcher.changed());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,2 +1,3 @@
 pub mod aoclib;
 pub mod aoc2025;
+pub mod registry;
@@ -1,20 +1,595 @@
-use adventofcode_rs::aoc2025;
-use adventofcode_rs::aoclib::runner::AocDay;
-
-fn main() {
-    let days: Vec<Box<dyn AocDay>> = vec![
-        Box::new(aoc2025::day01::AdventOfCode2025Day01::default()),
-        Box::new(aoc2025::day02::AdventOfCode2025Day02::default()),
-        Box::new(aoc2025::day03::AdventOfCode2025Day03::default()),
-        Box::new(aoc2025::day04::AdventOfCode2025Day04::default()),
-        Box::new(aoc2025::day05::AdventOfCode2025Day05::default()),
-        Box::new(aoc2025::day06::AdventOfCode2025Day06::default()),
-        Box::new(aoc2025::day07::AdventOfCode2025Day07::default()),
-        Box::new(aoc2025::day08::AdventOfCode2025Day08::default()),
-        Box::new(aoc2025::day09::AdventOfCode2025Day09::default()),
-    ];
+use adventofcode_rs::aoclib::alloc;
+use adventofcode_rs::aoclib::budget::{BUDGET_PATH, Budget};
+use adventofcode_rs::aoclib::cache::{self, ANSWER_CACHE_PATH, AnswerCache};
+use adventofcode_rs::aoclib::calendar::{self, DayState};
+use adventofcode_rs::aoclib::cli::Args;
+use adventofcode_rs::aoclib::client::{AocClient, ClientConfig};
+use adventofcode_rs::aoclib::crosscheck;
+use adventofcode_rs::aoclib::crypto::{self, InputKey};
+use adventofcode_rs::aoclib::extract;
+use adventofcode_rs::aoclib::fetch::{self, Fetched};
+use adventofcode_rs::aoclib::generate;
+use adventofcode_rs::aoclib::history::{self, Entry, HISTORY_PATH, History, Trend};
+use adventofcode_rs::aoclib::html::{self, HtmlRow};
+use adventofcode_rs::aoclib::minimize::{self, Predicate};
+use adventofcode_rs::aoclib::readme::{self, ProgressRow, README_PATH};
+use adventofcode_rs::aoclib::runner::RunOptions;
+use adventofcode_rs::aoclib::scaling::{self, Sample};
+use adventofcode_rs::aoclib::submit::{self, ATTEMPT_LOG_PATH, AttemptLog, Verdict};
+use adventofcode_rs::aoclib::watch;
+use adventofcode_rs::registry;
+use std::collections::BTreeMap;
+use std::io::IsTerminal;
+use std::path::PathBuf;
+use std::process::{Command, ExitCode};
+use std::time::Duration;
 
+const USAGE: &str =
+    "Usage: aoc [run] [--year Y] [--day N] [--timeout SECONDS] [--cached] [--variant NAME]
+       aoc watch --day N [--year Y] [--timeout SECONDS]
+       aoc fetch --day N [--year Y] [--force]
+       aoc submit --day N --part P [--year Y] [--timeout SECONDS]
+       aoc extract --day N --page PAGE.html [--out DIR] [--force]
+       aoc encrypt [--year Y] [--day N] [--new-key]
+       aoc crosscheck [--year Y] [--day N] [--timeout SECONDS]
+       aoc generate --day N --size S [--seed X] [--year Y] [--out FILE]
+       aoc minimize --day N --input FILE --predicate disagree|panic|parse-error [--year Y]
+                    [--out FILE] [--timeout SECONDS]
+       aoc scale [--year Y] [--day N] [--from S] [--steps K] [--seed X] [--repeats R]
+                 [--timeout SECONDS]
+       aoc perf [--year Y] [--day N] [--threshold PERCENT] [--window RUNS]
+       aoc report --html DIR [--year Y] [--day N] [--timeout SECONDS]
+       aoc readme
+       aoc calendar [--year Y] [--no-color]";
+
+fn main() -> ExitCode {
+    let result =
+        Args::parse(std::env::args().skip(1)).and_then(|args| match args.command.as_deref() {
+            None | Some("run") => run(&args),
+            Some("watch") => watch(&args),
+            Some("fetch") => fetch(&args),
+            Some("submit") => submit(&args),
+            Some("extract") => extract(&args),
+            Some("encrypt") => encrypt(&args),
+            Some("crosscheck") => crosscheck(&args),
+            Some("generate") => generate(&args),
+            Some("minimize") => minimize(&args),
+            Some("scale") => scale(&args),
+            Some("perf") => perf(&args),
+            Some("report") => report(&args),
+            Some("readme") => readme(&args),
+            Some("calendar") => calendar(&args),
+            Some(command) => Err(format!("Unknown command: '{}'\n{}", command, USAGE)),
+        });
+
+    match result {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: &Args) -> Result<ExitCode, String> {
+    let options = run_options(args)?;
+    let days = registry::select(args.parse_value("year")?, args.parse_value("day")?);
+    if days.is_empty() {
+        return Err("No registered days match the selection".to_string());
+    }
+    if let Some(variant) = &options.variant
+        && !days.iter().any(|day| {
+            [1, 2]
+                .into_iter()
+                .any(|part| day.variant_names(part).contains(&variant.as_str()))
+        })
+    {
+        return Err(format!("No selected day has a variant named '{}'", variant));
+    }
+
+    let mut answer_cache = AnswerCache::load(ANSWER_CACHE_PATH);
+    // Debug timings and variant runs aren't comparable with the release defaults.
+    let mut history = Some(History::load(HISTORY_PATH))
+        .filter(|_| !cfg!(debug_assertions) && options.variant.is_none());
+    let budget = Budget::load(BUDGET_PATH)?;
+    let mut reports = vec![];
     for day in days {
-        println!("{}", day.run_day());
+        let report = cache::run_day(
+            day.as_ref(),
+            &options,
+            &mut answer_cache,
+            args.has("cached"),
+        )?;
+        if let (Some(history), Some(build)) = (history.as_mut(), cache::build_id()) {
+            let entries = Entry::from_report(&report, history::current_commit(), build);
+            if let Err(e) = history.append(entries) {
+                eprintln!("Timings not recorded: {}", e);
+            }
+        }
+        println!("{}", report);
+        // Days without an input never ran, so they have no cost to count.
+        if report.fingerprint.is_some() {
+            reports.push(report);
+        }
+    }
+
+    if budget == Budget::default() || args.has("cached") {
+        return Ok(ExitCode::SUCCESS);
+    }
+    if cfg!(debug_assertions) {
+        println!("The runtime budget is only checked in release builds");
+        return Ok(ExitCode::SUCCESS);
+    }
+    let mut code = ExitCode::SUCCESS;
+    for check in budget.check(&reports) {
+        print!("{}", check);
+        if check.exceeded() {
+            code = ExitCode::FAILURE;
+        }
+    }
+    Ok(code)
+}
+
+fn watch(args: &Args) -> Result<ExitCode, String> {
+    let options = run_options(args)?;
+    let day_num = args
+        .parse_value::<u32>("day")?
+        .ok_or_else(|| format!("watch requires --day N\n{}", USAGE))?;
+    let day = registry::find(args.parse_value("year")?, day_num)
+        .ok_or_else(|| format!("Day {} is not registered", day_num))?;
+
+    watch::watch(
+        day.as_ref(),
+        &options,
+        Duration::from_millis(500),
+        |source| {
+            println!("{} changed, rebuilding...", source.display());
+            rebuild_and_restart()
+        },
+    )
+}
+
+fn fetch(args: &Args) -> Result<ExitCode, String> {
+    let day_num = args
+        .parse_value::<u32>("day")?
+        .ok_or_else(|| format!("fetch requires --day N\n{}", USAGE))?;
+    let year = match args.parse_value::<u32>("year")? {
+        Some(year) => year,
+        None => *registry::years()
+            .last()
+            .ok_or_else(|| format!("No days are registered, pass --year Y\n{}", USAGE))?,
+    };
+    let mut client = AocClient::new(ClientConfig::load()?);
+
+    let input_path = fetch::input_path(year, day_num);
+    match fetch::fetch_cached(&mut client, year, day_num, &input_path, args.has("force"))? {
+        Fetched::Cached => println!("{} day {:02}: cached", year, day_num),
+        Fetched::Downloaded => {
+            println!("{} day {:02}: downloaded to {}", year, day_num, input_path)
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn submit(args: &Args) -> Result<ExitCode, String> {
+    let day_num = args
+        .parse_value::<u32>("day")?
+        .ok_or_else(|| format!("submit requires --day N\n{}", USAGE))?;
+    let day = registry::find(args.parse_value("year")?, day_num)
+        .ok_or_else(|| format!("Day {} is not registered", day_num))?;
+    let part = match args.parse_value::<u32>("part")? {
+        Some(part @ (1 | 2)) => part,
+        _ => return Err(format!("submit requires --part 1 or --part 2\n{}", USAGE)),
+    };
+
+    let report = day.run_day(&run_options(args)?);
+    let phase = match part {
+        1 => report.part01.as_ref(),
+        _ => report.part02.as_ref(),
+    };
+    let answer = phase
+        .and_then(|phase| phase.answer())
+        .ok_or_else(|| format!("No answer to submit:\n{}", report))?;
+
+    let (year, _) = day.year_day();
+    let mut client = AocClient::new(ClientConfig::load()?);
+    let mut log = AttemptLog::load(ATTEMPT_LOG_PATH)?;
+    let verdict = submit::submit(
+        &mut client,
+        &mut log,
+        (year, day_num, part),
+        day.part_status(part),
+        answer,
+    )?;
+    println!(
+        "{} day {:02} part {:02}: {} is {}",
+        year, day_num, part, answer, verdict
+    );
+
+    Ok(match verdict {
+        Verdict::Correct => ExitCode::SUCCESS,
+        _ => ExitCode::FAILURE,
+    })
+}
+
+fn extract(args: &Args) -> Result<ExitCode, String> {
+    let day = args
+        .parse_value::<u32>("day")?
+        .ok_or_else(|| format!("extract requires --day N\n{}", USAGE))?;
+    let page_path = args
+        .value("page")
+        .ok_or_else(|| format!("extract requires --page PAGE.html\n{}", USAGE))?;
+    let out = args
+        .value("out")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(format!("input/test/day{:02}", day)));
+
+    let html = std::fs::read_to_string(page_path)
+        .map_err(|e| format!("Failed to read {}: {}", page_path, e))?;
+    let page = extract::parse_page(&html);
+    if let Some(title) = &page.title {
+        println!("Day {:02}: {}", day, title);
+    }
+    println!(
+        "Found {} example block(s) and {} answer(s)",
+        page.examples.len(),
+        page.answers.len()
+    );
+    for path in extract::write_candidates(&page, &out, args.has("force"))? {
+        println!("Wrote {}", path.display());
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Encrypt the plaintext inputs of the selected days to `<input>.enc`, so they can be committed.
+/// With `--new-key`, print a fresh key to share instead.
+fn encrypt(args: &Args) -> Result<ExitCode, String> {
+    if args.has("new-key") {
+        println!("{}", InputKey::generate().to_hex());
+        return Ok(ExitCode::SUCCESS);
+    }
+    let key = InputKey::from_env()?.ok_or_else(|| {
+        format!(
+            "encrypt requires the {} environment variable",
+            crypto::KEY_VAR
+        )
+    })?;
+    let days = registry::select(args.parse_value("year")?, args.parse_value("day")?);
+
+    for day in days {
+        let input_path = day.input_path();
+        let Ok(input) = std::fs::read(&input_path) else {
+            continue;
+        };
+        let encrypted_path = crypto::encrypted_path(&input_path);
+        std::fs::write(&encrypted_path, key.encrypt(&input))
+            .map_err(|e| format!("Failed to write {}: {}", encrypted_path, e))?;
+        println!("Wrote {}", encrypted_path);
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Show the recorded timings of the selected days, failing when a phase got slower than the
+/// median of its recent runs by more than `--threshold` percent.
+fn perf(args: &Args) -> Result<ExitCode, String> {
+    let year = args.parse_value::<u32>("year")?;
+    let day = args.parse_value::<u32>("day")?;
+    let threshold = args.parse_value::<f64>("threshold")?.unwrap_or(10.0) / 100.0;
+    let window = args.parse_value::<usize>("window")?.unwrap_or(5);
+    let trends: Vec<Trend> = History::load(HISTORY_PATH)
+        .trends(window)
+        .into_iter()
+        .filter(|trend| {
+            year.is_none_or(|year| year == trend.year) && day.is_none_or(|day| day == trend.day)
+        })
+        .collect();
+    if trends.is_empty() {
+        println!(
+            "No timings recorded in {}, run `cargo run --release` first",
+            HISTORY_PATH
+        );
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let mut code = ExitCode::SUCCESS;
+    for trend in trends {
+        if trend.regressed(threshold) {
+            println!("{}  SLOWER", trend);
+            code = ExitCode::FAILURE;
+        } else {
+            println!("{}", trend);
+        }
+    }
+    Ok(code)
+}
+
+/// Run the selected days and write their answers, verification status and timings as a
+/// self-contained HTML page.
+fn report(args: &Args) -> Result<ExitCode, String> {
+    let options = run_options(args)?;
+    let dir = PathBuf::from(
+        args.value("html")
+            .ok_or_else(|| format!("report requires --html DIR\n{}", USAGE))?,
+    );
+    let log = AttemptLog::load(ATTEMPT_LOG_PATH)?;
+    let days = registry::select(args.parse_value("year")?, args.parse_value("day")?);
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+
+    let rows: Vec<HtmlRow> = days
+        .iter()
+        .map(|day| {
+            let report = day.run_day(&options);
+            let (year, day_num) = day.year_day();
+            let phases = [&report.part01, &report.part02];
+            let parts = [1, 2].map(|part| {
+                let answer = phases[part as usize - 1]
+                    .as_ref()
+                    .and_then(|phase| phase.answer());
+                log.verify((year, day_num, part), day.part_status(part), answer)
+            });
+            let criterion = html::criterion_report(year, day_num)
+                .and_then(|path| html::relative_link(&dir, &path));
+            HtmlRow {
+                report,
+                parts,
+                criterion,
+            }
+        })
+        .collect();
+
+    let path = html::write_report(&dir, &rows, history::current_commit())?;
+    println!("Wrote {}", path.display());
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Show a year's days as a calendar, coloured by how far along each day is.
+fn calendar(args: &Args) -> Result<ExitCode, String> {
+    let year = match args.parse_value::<u32>("year")? {
+        Some(year) => year,
+        None => *registry::years().last().ok_or("No days are registered")?,
+    };
+    let log = AttemptLog::load(ATTEMPT_LOG_PATH)?;
+    let states: Vec<DayState> = (1..=calendar::event_days(year))
+        .map(|day_num| {
+            let parts = registry::find(Some(year), day_num)
+                .map(|day| [1, 2].map(|part| day.part_status(part)));
+            let accepted = [1, 2].map(|part| log.correct_answer(year, day_num, part).is_some());
+            DayState::of(parts, accepted)
+        })
+        .collect();
+
+    let colour = std::io::stdout().is_terminal()
+        && std::env::var_os("NO_COLOR").is_none()
+        && !args.has("no-color");
+    print!("{}", calendar::render(year, &states, colour));
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Rewrite the progress table in the README from the registry and the latest recorded timings.
+fn readme(_args: &Args) -> Result<ExitCode, String> {
+    let mut times: BTreeMap<(u32, u32), Duration> = BTreeMap::new();
+    for trend in History::load(HISTORY_PATH).trends(0) {
+        *times.entry((trend.year, trend.day)).or_default() += trend.latest;
+    }
+    let rows: Vec<ProgressRow> = registry::days()
+        .iter()
+        .map(|day| {
+            let (year, day_num) = day.year_day();
+            ProgressRow {
+                year,
+                day: day_num,
+                title: day.title(),
+                parts: [1, 2].map(|part| day.part_status(part)),
+                time: times.get(&(year, day_num)).copied(),
+            }
+        })
+        .collect();
+
+    let contents = std::fs::read_to_string(README_PATH)
+        .map_err(|e| format!("Failed to read {}: {}", README_PATH, e))?;
+    let updated = readme::replace_section(&contents, &readme::table(&rows))?;
+    if updated == contents {
+        println!("{} is up to date", README_PATH);
+    } else {
+        std::fs::write(README_PATH, updated)
+            .map_err(|e| format!("Failed to write {}: {}", README_PATH, e))?;
+        println!("Updated the progress table in {}", README_PATH);
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Run every variant of the selected days' parts on their inputs and examples, failing when
+/// variants disagree.
+fn crosscheck(args: &Args) -> Result<ExitCode, String> {
+    let options = run_options(args)?;
+    let days = registry::select(args.parse_value("year")?, args.parse_value("day")?);
+    let mut code = ExitCode::SUCCESS;
+
+    for day in days
+        .iter()
+        .filter(|day| crosscheck::has_variants(day.as_ref()))
+    {
+        let (year, day_num) = day.year_day();
+        for check in crosscheck::crosscheck(day.as_ref(), &options)? {
+            if check.problems.is_empty() {
+                println!("{} day {:02} {}: ok", year, day_num, check.label);
+                continue;
+            }
+            code = ExitCode::FAILURE;
+            for problem in check.problems {
+                println!("{} day {:02} {}: {}", year, day_num, check.label, problem);
+            }
+        }
+    }
+    Ok(code)
+}
+
+/// Write a seeded synthetic input for a day to `--out`, or to stdout.
+fn generate(args: &Args) -> Result<ExitCode, String> {
+    let day_num = args
+        .parse_value::<u32>("day")?
+        .ok_or_else(|| format!("generate requires --day N\n{}", USAGE))?;
+    let size = args
+        .parse_value::<usize>("size")?
+        .ok_or_else(|| format!("generate requires --size S\n{}", USAGE))?;
+    let seed = args.parse_value::<u64>("seed")?.unwrap_or(0);
+    let day = registry::find(args.parse_value("year")?, day_num)
+        .ok_or_else(|| format!("Day {} is not registered", day_num))?;
+
+    let input = generate::generate(day.as_ref(), size, seed)?;
+    match args.value("out") {
+        Some(out) => {
+            std::fs::write(out, input).map_err(|e| format!("Failed to write {}: {}", out, e))?
+        }
+        None => print!("{}", input),
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Shrink an input while it still shows a problem, and write the minimal reproducer to `--out`,
+/// by default next to the input with a `.min` extension.
+fn minimize(args: &Args) -> Result<ExitCode, String> {
+    let options = run_options(args)?;
+    let day_num = args
+        .parse_value::<u32>("day")?
+        .ok_or_else(|| format!("minimize requires --day N\n{}", USAGE))?;
+    let input_path = args
+        .value("input")
+        .ok_or_else(|| format!("minimize requires --input FILE\n{}", USAGE))?;
+    let predicate = args
+        .parse_value::<Predicate>("predicate")?
+        .ok_or_else(|| format!("minimize requires --predicate\n{}", USAGE))?;
+    let day = registry::find(args.parse_value("year")?, day_num)
+        .ok_or_else(|| format!("Day {} is not registered", day_num))?;
+    let out = args
+        .value("out")
+        .map(str::to_string)
+        .unwrap_or_else(|| format!("{}.min", input_path));
+
+    let input = std::fs::read_to_string(input_path)
+        .map_err(|e| format!("Failed to read {}: {}", input_path, e))?;
+    let minimal = minimize::minimize(&input, |candidate| {
+        predicate.holds(day.as_ref(), candidate, &options)
+    })?;
+    std::fs::write(&out, &minimal).map_err(|e| format!("Failed to write {}: {}", out, e))?;
+    println!(
+        "Minimized {} ({} bytes) to {} ({} bytes)",
+        input_path,
+        input.len(),
+        out,
+        minimal.len()
+    );
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Picks one phase's duration out of a scaling sample.
+type PhaseTime = fn(&Sample) -> Option<Duration>;
+
+/// Run the selected days on generated inputs of doubling sizes, fit how each phase's time grows
+/// with the input length and check it against the complexity class the day declares.
+fn scale(args: &Args) -> Result<ExitCode, String> {
+    let options = run_options(args)?;
+    let from = args.parse_value::<usize>("from")?.unwrap_or(250);
+    let steps = args.parse_value::<u32>("steps")?.unwrap_or(4);
+    let seed = args.parse_value::<u64>("seed")?.unwrap_or(0);
+    let repeats = args.parse_value::<usize>("repeats")?.unwrap_or(3);
+    let sizes: Vec<usize> = (0..steps).map(|step| from << step).collect();
+    let days = registry::select(args.parse_value("year")?, args.parse_value("day")?);
+    let mut code = ExitCode::SUCCESS;
+
+    for day in days {
+        let (year, day_num) = day.year_day();
+        println!("{} day {:02}", year, day_num);
+        let samples = match scaling::measure(day.as_ref(), &sizes, seed, repeats, &options) {
+            Ok(samples) => samples,
+            Err(e) => {
+                eprintln!("\t{}", e);
+                code = ExitCode::FAILURE;
+                continue;
+            }
+        };
+        for sample in &samples {
+            println!(
+                "\tsize {:>8} ({:>10}): parse {:?}, part 01 {:?}, part 02 {:?}",
+                sample.size,
+                alloc::format_bytes(sample.bytes),
+                sample.parse,
+                sample.part01.unwrap_or_default(),
+                sample.part02.unwrap_or_default()
+            );
+        }
+
+        let phases: [(&str, Option<u32>, PhaseTime); 3] = [
+            ("Parse", None, |sample| Some(sample.parse)),
+            ("Part 01", Some(1), |sample| sample.part01),
+            ("Part 02", Some(2), |sample| sample.part02),
+        ];
+        for (label, part, phase) in phases {
+            let Some(exponent) = scaling::phase_exponent(&samples, phase) else {
+                continue;
+            };
+            match part.and_then(|part| day.complexity(part)) {
+                Some(class) if class.allows(exponent) => {
+                    println!("\t{}: n^{:.2}, within {}", label, exponent, class)
+                }
+                Some(class) => {
+                    println!("\t{}: n^{:.2}, EXCEEDS {}", label, exponent, class);
+                    code = ExitCode::FAILURE;
+                }
+                None => println!("\t{}: n^{:.2}", label, exponent),
+            }
+        }
+    }
+    Ok(code)
+}
+
+fn run_options(args: &Args) -> Result<RunOptions, String> {
+    let part_timeout = args
+        .parse_value::<f64>("timeout")?
+        .map(|seconds| {
+            Duration::try_from_secs_f64(seconds)
+                .map_err(|_| format!("Invalid value for --timeout: '{}'", seconds))
+        })
+        .transpose()?;
+    Ok(RunOptions {
+        part_timeout,
+        variant: args.value("variant").map(str::to_string),
+    })
+}
+
+/// Rebuild this binary with the same profile and features, then replace the running process
+/// with the new build using the same arguments.
+fn rebuild_and_restart() -> Result<(), String> {
+    let mut build = Command::new("cargo");
+    build.args(["build", "--bin", "aoc"]);
+    if !cfg!(debug_assertions) {
+        build.arg("--release");
+    }
+    if cfg!(feature = "count-allocations") {
+        build.args(["--features", "count-allocations"]);
+    }
+    let status = build
+        .status()
+        .map_err(|e| format!("Failed to run cargo: {}", e))?;
+    if !status.success() {
+        return Err("Build failed, waiting for the next change".to_string());
+    }
+
+    let exe = std::env::current_exe().map_err(|e| e.to_string())?;
+    let mut restart = Command::new(exe);
+    restart.args(std::env::args().skip(1));
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        Err(format!("Failed to restart: {}", restart.exec()))
+    }
+    #[cfg(not(unix))]
+    {
+        let status = restart
+            .status()
+            .map_err(|e| format!("Failed to restart: {}", e))?;
+        std::process::exit(status.code().unwrap_or(1));
     }
 }
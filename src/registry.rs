@@ -0,0 +1,54 @@
+use crate::aoc2025;
+use crate::aoclib::runner::AocDay;
+
+/// Every registered day of every year, ordered by year and day.
+pub fn days() -> Vec<Box<dyn AocDay>> {
+    aoc2025::days()
+}
+
+/// Every registered year, in order.
+pub fn years() -> Vec<u32> {
+    let mut years: Vec<u32> = days().iter().map(|day| day.year_day().0).collect();
+    years.dedup();
+    years
+}
+
+/// Find a registered day. Without a `year`, the latest year is searched.
+pub fn find(year: Option<u32>, day: u32) -> Option<Box<dyn AocDay>> {
+    let year = year.or_else(|| years().last().copied())?;
+    days()
+        .into_iter()
+        .find(|registered| registered.year_day() == (year, day))
+}
+
+/// Select days by optional year and day filters.
+pub fn select(year: Option<u32>, day: Option<u32>) -> Vec<Box<dyn AocDay>> {
+    days()
+        .into_iter()
+        .filter(|registered| {
+            let (registered_year, registered_day) = registered.year_day();
+            year.is_none_or(|year| year == registered_year)
+                && day.is_none_or(|day| day == registered_day)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry;
+
+    #[test]
+    fn test_find() {
+        assert_eq!((2025, 7), registry::find(None, 7).unwrap().year_day());
+        assert_eq!((2025, 1), registry::find(Some(2025), 1).unwrap().year_day());
+        assert!(registry::find(Some(2015), 1).is_none());
+        assert!(registry::find(None, 26).is_none());
+    }
+
+    #[test]
+    fn test_select() {
+        assert_eq!(9, registry::select(Some(2025), None).len());
+        assert_eq!(1, registry::select(None, Some(3)).len());
+        assert!(registry::select(Some(2015), None).is_empty());
+    }
+}
@@ -0,0 +1,46 @@
+//! Runs every example in `input/test/dayNN/` through its day and checks the answers in the
+//! matching `.expected` file. Parts that are unsolved or return a placeholder are ignored.
+
+use adventofcode_rs::aoclib::examples;
+use adventofcode_rs::aoclib::runner::{PartStatus, RunOptions};
+use adventofcode_rs::registry;
+use libtest_mimic::{Arguments, Failed, Trial};
+
+fn main() {
+    let args = Arguments::from_args();
+    let mut trials = vec![];
+
+    for day in registry::days() {
+        let (year, day_num) = day.year_day();
+        let examples = match examples::load(day.examples_dir()) {
+            Ok(examples) => examples,
+            Err(e) => {
+                let name = format!("{}::day{:02}::examples", year, day_num);
+                trials.push(Trial::test(name, move || Err(Failed::from(e))));
+                continue;
+            }
+        };
+
+        for example in examples {
+            for part in [1, 2] {
+                if example.expected.part(part).is_none() {
+                    continue;
+                }
+                let name = format!(
+                    "{}::day{:02}::{}::part{:02}",
+                    year, day_num, example.name, part
+                );
+                let ignored = day.part_status(part) != PartStatus::Solved;
+                let example = example.clone();
+                let trial = Trial::test(name, move || {
+                    let day = registry::find(Some(year), day_num).expect("registered day");
+                    let report = example.run(day.as_ref(), &RunOptions::default())?;
+                    Ok(example.verify(&report, part)?)
+                });
+                trials.push(trial.with_ignored_flag(ignored));
+            }
+        }
+    }
+
+    libtest_mimic::run(&args, trials).exit();
+}
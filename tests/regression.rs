@@ -0,0 +1,57 @@
+//! Runs every registered day on its real puzzle input and checks the answers accepted in the
+//! attempt log. Real inputs are only committed encrypted, so days without an input (or without
+//! `AOC_INPUT_KEY` to decrypt it) or an accepted answer are reported as ignored instead of failing.
+
+use adventofcode_rs::aoclib::input;
+use adventofcode_rs::aoclib::runner::RunOptions;
+use adventofcode_rs::aoclib::submit::{ATTEMPT_LOG_PATH, AttemptLog};
+use adventofcode_rs::registry;
+use libtest_mimic::{Arguments, Completion, Failed, Trial};
+
+fn check_day(year: u32, day_num: u32) -> Result<Completion, Failed> {
+    let day = registry::find(Some(year), day_num).expect("registered day");
+    if !input::exists(&day.input_path()) {
+        return Ok(Completion::ignored_with(format!(
+            "no input at {}",
+            day.input_path()
+        )));
+    }
+
+    let log = AttemptLog::load(ATTEMPT_LOG_PATH)?;
+    let expected: Vec<(u32, &str)> = [1, 2]
+        .into_iter()
+        .filter_map(|part| Some((part, log.correct_answer(year, day_num, part)?)))
+        .collect();
+    if expected.is_empty() {
+        return Ok(Completion::ignored_with(format!(
+            "no accepted answers in {}",
+            ATTEMPT_LOG_PATH
+        )));
+    }
+
+    let report = day.run_day(&RunOptions::default());
+    for (part, answer) in expected {
+        let phase = match part {
+            1 => report.part01.as_ref(),
+            _ => report.part02.as_ref(),
+        };
+        if phase.and_then(|phase| phase.answer()) != Some(answer) {
+            return Err(format!("part {:02} expected {}\n{}", part, answer, report).into());
+        }
+    }
+    Ok(Completion::Completed)
+}
+
+fn main() {
+    let args = Arguments::from_args();
+    let trials = registry::days()
+        .into_iter()
+        .map(|day| {
+            let (year, day_num) = day.year_day();
+            let name = format!("{}::day{:02}", year, day_num);
+            Trial::ignorable_test(name, move || check_day(year, day_num))
+        })
+        .collect();
+
+    libtest_mimic::run(&args, trials).exit();
+}